@@ -0,0 +1,84 @@
+pub use aoc_examples::Answer;
+use serde::Deserialize;
+use std::{collections::BTreeMap, fs, path::Path};
+
+// The answers of the examples are in the examples.toml of each day.
+#[derive(Debug, Default, Deserialize)]
+pub struct PartAnswers {
+    pub input: Option<Answer>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DayAnswers {
+    pub part1: Option<PartAnswers>,
+    pub part2: Option<PartAnswers>,
+}
+
+impl DayAnswers {
+    pub fn part(&self, part: usize) -> Option<&PartAnswers> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+impl Answers {
+    pub fn load<P>(filename: P) -> Result<Answers, String>
+    where
+        P: AsRef<Path>,
+    {
+        let content = match fs::read_to_string(&filename) {
+            Ok(content) => content,
+            Err(e) => {
+                return Err(format!(
+                    "Failed reading {}: {}",
+                    filename.as_ref().display(),
+                    e
+                ))
+            }
+        };
+        Answers::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Answers, String> {
+        match toml::from_str(content) {
+            Ok(days) => Ok(Answers { days }),
+            Err(e) => Err(format!("Failed parsing answers: {}", e)),
+        }
+    }
+
+    pub fn get(&self, directory: &str, part: usize) -> Option<&PartAnswers> {
+        self.days.get(directory).and_then(|day| day.part(part))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, Answers};
+
+    #[test]
+    fn parse_numbers_and_text() {
+        let answers = Answers::parse(
+            r#"
+            [day_01]
+            part1 = { input = "abc" }
+            part2 = { input = 281 }
+            "#,
+        )
+        .unwrap();
+        let part = answers.get("day_01", 1).unwrap();
+        assert_eq!(part.input, Some(Answer::Text(String::from("abc"))));
+        assert_eq!(
+            answers.get("day_01", 2).unwrap().input,
+            Some(Answer::Number(281))
+        );
+        assert!(answers.get("day_02", 1).is_none());
+    }
+}
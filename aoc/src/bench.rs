@@ -0,0 +1,97 @@
+use crate::{
+    days::{Day, RunOptions},
+    timing::{format_duration, Stats},
+};
+use serde::Serialize;
+use std::{fs, path::Path, time::Duration};
+
+#[derive(Debug, Serialize)]
+pub struct BenchResult {
+    pub day: u8,
+    pub part: usize,
+    pub input: String,
+    pub answer: String,
+    pub warmup: usize,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+pub fn bench(
+    day: &Day,
+    part: usize,
+    path: &Path,
+    warmup: usize,
+    runs: usize,
+    options: &RunOptions,
+) -> Result<BenchResult, String> {
+    let solve = |path| day.parts[part - 1].run(path, options);
+    for _ in 0..warmup {
+        solve(path)?;
+    }
+
+    let mut answer = String::new();
+    let mut parse_times = Vec::with_capacity(runs);
+    let mut solve_times = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let solved = solve(path)?;
+        parse_times.push(solved.parse);
+        solve_times.push(solved.solve);
+        answer = solved.answer;
+    }
+
+    match (
+        Stats::from_durations(&parse_times),
+        Stats::from_durations(&solve_times),
+    ) {
+        (Some(parse), Some(solve)) => Ok(BenchResult {
+            day: day.number,
+            part,
+            input: path.display().to_string(),
+            answer,
+            warmup,
+            runs,
+            parse,
+            solve,
+        }),
+        _ => Err(String::from("At least one run is required")),
+    }
+}
+
+pub fn print_table(results: &[BenchResult]) {
+    println!(
+        "{:>3}  {:>4}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Part", "Runs", "Parse", "Mean", "Median", "Stddev", "Min"
+    );
+    for result in results {
+        println!(
+            "{:>3}  {:>4}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}  {:>10}",
+            result.day,
+            result.part,
+            result.runs,
+            format_duration(Duration::from_secs_f64(result.parse.median)),
+            format_duration(Duration::from_secs_f64(result.solve.mean)),
+            format_duration(Duration::from_secs_f64(result.solve.median)),
+            format_duration(Duration::from_secs_f64(result.solve.stddev)),
+            format_duration(Duration::from_secs_f64(result.solve.min)),
+        );
+    }
+}
+
+pub fn write_json<P>(results: &[BenchResult], filename: P) -> Result<(), String>
+where
+    P: AsRef<Path>,
+{
+    let json = match serde_json::to_string_pretty(results) {
+        Ok(json) => json,
+        Err(e) => return Err(format!("Failed serializing benchmark results: {}", e)),
+    };
+    match fs::write(&filename, json) {
+        Ok(()) => Ok(()),
+        Err(e) => Err(format!(
+            "Failed writing {}: {}",
+            filename.as_ref().display(),
+            e
+        )),
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{
+    days::{workspace_root, Day, Integer, RunOptions},
+    timing::Solved,
+};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// What an answer depends on besides the code of the runner. An entry whose
+/// key differs from the current one is stale.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Key {
+    pub hash: String,
+    pub version: u32,
+    pub integer: Integer,
+}
+
+/// A solved part with the times of the run that solved it, in seconds.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Entry {
+    #[serde(flatten)]
+    pub key: Key,
+    pub answer: String,
+    pub parse: f64,
+    pub solve: f64,
+}
+
+/// The last answer of every part of the real inputs, by day directory and
+/// part, so that an entry is replaced rather than joined by a new one once
+/// its input or solver change.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Cache {
+    entries: BTreeMap<String, Entry>,
+}
+
+// Lives in the build directory, as it is as easy to rebuild as the runner.
+pub fn path() -> PathBuf {
+    workspace_root().join("target").join("aoc_cache.json")
+}
+
+// The SHA-256 of the file at `path` in hex.
+fn hash(path: &Path) -> Result<String, String> {
+    match fs::read(path) {
+        Ok(content) => Ok(format!("{:x}", Sha256::digest(&content))),
+        Err(e) => Err(format!("Failed reading {}: {}", path.display(), e)),
+    }
+}
+
+fn slot(day: &Day, part: usize) -> String {
+    format!("{} part {}", day.directory, part)
+}
+
+impl Cache {
+    /// The cache at `path`. A missing or unreadable cache is an empty one, it
+    /// only costs solving everything again.
+    pub fn load(path: &Path) -> Cache {
+        let Ok(content) = fs::read_to_string(path) else {
+            debug!("No answers cached at {}", path.display());
+            return Cache::default();
+        };
+        match serde_json::from_str(&content) {
+            Ok(cache) => cache,
+            Err(e) => {
+                warn!("Ignoring the answer cache {}: {}", path.display(), e);
+                Cache::default()
+            }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = match serde_json::to_string_pretty(self) {
+            Ok(json) => json,
+            Err(e) => return Err(format!("Failed serializing the answer cache: {}", e)),
+        };
+        if let Some(directory) = path.parent() {
+            if let Err(e) = fs::create_dir_all(directory) {
+                return Err(format!("Failed creating {}: {}", directory.display(), e));
+            }
+        }
+        match fs::write(path, json) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(format!("Failed writing {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn get(&self, slot: &str, key: &Key) -> Option<&Entry> {
+        self.entries.get(slot).filter(|entry| entry.key == *key)
+    }
+
+    pub fn insert(&mut self, slot: String, key: Key, solved: &Solved) {
+        self.entries.insert(
+            slot,
+            Entry {
+                key,
+                answer: solved.answer.clone(),
+                parse: solved.parse.as_secs_f64(),
+                solve: solved.solve.as_secs_f64(),
+            },
+        );
+    }
+
+    /// Solves a part of `day` on the input at `path`, unless the cache holds
+    /// its answer for the same input, solver version and integer type and
+    /// `fresh` is not set. Also returns whether the answer came from the
+    /// cache. Errors are not cached.
+    pub fn solve(
+        &mut self,
+        day: &Day,
+        part: usize,
+        path: &Path,
+        fresh: bool,
+        options: &RunOptions,
+    ) -> Result<(Solved, bool), String> {
+        let slot = slot(day, part);
+        let key = Key {
+            hash: hash(path)?,
+            version: day.version,
+            integer: options.integer,
+        };
+        if let Some(entry) = self.get(&slot, &key).filter(|_| !fresh) {
+            debug!("{}: cached answer {}", slot, entry.answer);
+            let solved = Solved {
+                answer: entry.answer.clone(),
+                parse: Duration::from_secs_f64(entry.parse),
+                solve: Duration::from_secs_f64(entry.solve),
+            };
+            return Ok((solved, true));
+        }
+        let solved = day.parts[part - 1].run(path, options)?;
+        self.insert(slot, key, &solved);
+        Ok((solved, false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cache, Key};
+    use crate::{
+        days::{Integer, RunOptions, DAYS},
+        timing::Solved,
+    };
+    use std::{env, fs, time::Duration};
+
+    fn key(hash: &str, version: u32, integer: Integer) -> Key {
+        Key {
+            hash: String::from(hash),
+            version,
+            integer,
+        }
+    }
+
+    #[test]
+    fn answers_are_stale_once_their_key_changes() {
+        let mut cache = Cache::default();
+        let solved = Solved {
+            answer: String::from("26829166"),
+            parse: Duration::from_millis(1),
+            solve: Duration::from_millis(500),
+        };
+        cache.insert(
+            String::from("day_05 part 2"),
+            key("ab12", 1, Integer::U128),
+            &solved,
+        );
+
+        let slot = "day_05 part 2";
+        assert_eq!(
+            cache
+                .get(slot, &key("ab12", 1, Integer::U128))
+                .map(|entry| entry.answer.as_str()),
+            Some("26829166")
+        );
+        assert!(cache.get(slot, &key("cd34", 1, Integer::U128)).is_none());
+        assert!(cache.get(slot, &key("ab12", 2, Integer::U128)).is_none());
+        assert!(cache.get(slot, &key("ab12", 1, Integer::Big)).is_none());
+        assert!(cache
+            .get("day_05 part 1", &key("ab12", 1, Integer::U128))
+            .is_none());
+    }
+
+    #[test]
+    fn overflowing_answers_are_errors_and_not_cached() {
+        let path = env::temp_dir().join(format!("aoc_overflow_test_{}", std::process::id()));
+        fs::write(&path, day_04::gen::generate_with_matches(200, 7, 10)).unwrap();
+        let options = RunOptions {
+            integer: Integer::U32,
+            ..RunOptions::default()
+        };
+        let mut cache = Cache::default();
+        assert_eq!(
+            cache.solve(&DAYS[3], 2, &path, false, &options).err(),
+            Some(String::from("answer overflows u32"))
+        );
+        assert!(cache.entries.is_empty());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn cache_round_trips_and_survives_corruption() {
+        let path = env::temp_dir().join(format!("aoc_cache_test_{}.json", std::process::id()));
+        let mut cache = Cache::default();
+        let solved = Solved {
+            answer: String::from("142"),
+            parse: Duration::ZERO,
+            solve: Duration::from_micros(3),
+        };
+        cache.insert(
+            String::from("day_01 part 1"),
+            key("ff", 1, Integer::U64),
+            &solved,
+        );
+        cache.save(&path).unwrap();
+        let loaded = Cache::load(&path);
+        let entry = loaded.get("day_01 part 1", &key("ff", 1, Integer::U64));
+        assert_eq!(entry.map(|entry| entry.solve), Some(0.000003));
+
+        fs::write(&path, "{ not json").unwrap();
+        assert!(Cache::load(&path).entries.is_empty());
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,419 @@
+use crate::timing::{timed, Solved};
+use aoc_examples::Examples;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Reader {
+    /// Read the input line by line
+    Lines,
+    /// Read the whole input at once and parse it as bytes
+    Bytes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Integer {
+    /// Sum into a u32
+    U32,
+    /// Sum into a u64
+    U64,
+    /// Sum into a u128
+    U128,
+    /// Sum into an unbounded integer, which cannot overflow
+    Big,
+}
+
+/// The options of the command line that change how a part is solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Threads for solvers that run in parallel, 0 meaning one per CPU.
+    pub threads: usize,
+    /// How solvers read their input.
+    pub reader: Reader,
+    /// Whether long running solvers show their progress on stderr.
+    pub progress: bool,
+    /// The integer type days 1 to 4 sum their answers into. Day 5 answers
+    /// the lowest of its locations rather than a sum, a value of the input
+    /// that the parser reads as a `u64` already.
+    pub integer: Integer,
+}
+
+impl Default for RunOptions {
+    fn default() -> RunOptions {
+        RunOptions {
+            threads: 0,
+            reader: Reader::Lines,
+            progress: false,
+            integer: Integer::Big,
+        }
+    }
+}
+
+// Calls a solver with the integer type `integer` and turns the answer into a
+// string. The trailing `?` passes on the error of the solver, like an answer
+// that overflows `integer`.
+macro_rules! answer {
+    ($integer:expr, $($segment:ident)::+ ($($arg:expr),*)?) => {
+        match $integer {
+            Integer::U32 => $($segment)::+::<u32>($($arg),*)?.to_string(),
+            Integer::U64 => $($segment)::+::<u64>($($arg),*)?.to_string(),
+            Integer::U128 => $($segment)::+::<u128>($($arg),*)?.to_string(),
+            Integer::Big => $($segment)::+::<num_bigint::BigUint>($($arg),*)?.to_string(),
+        }
+    };
+}
+
+pub type Solver = fn(&Path, &RunOptions) -> Result<Solved, String>;
+
+pub struct Part {
+    pub solve: Solver,
+    pub solve_bytes: Solver,
+}
+
+impl Part {
+    // Solves the part on the input at `path` with the reader of `options`.
+    pub fn run(&self, path: &Path, options: &RunOptions) -> Result<Solved, String> {
+        match options.reader {
+            Reader::Lines => (self.solve)(path, options),
+            Reader::Bytes => (self.solve_bytes)(path, options),
+        }
+    }
+}
+
+pub struct Day {
+    pub number: u8,
+    pub directory: &'static str,
+    pub input: &'static str,
+    // Bump when a solver of the day changes, so that cached answers of the
+    // old solver are not used.
+    pub version: u32,
+    pub parts: [Part; 2],
+    pub generate: fn(usize, u64) -> String,
+}
+
+impl Day {
+    // The examples listed in the examples.toml of the day.
+    pub fn examples(&self) -> Result<Examples, String> {
+        Examples::load(workspace_root().join(self.directory))
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        workspace_root().join(self.directory).join(self.input)
+    }
+}
+
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate lives inside the workspace")
+        .to_path_buf()
+}
+
+fn day_01_part_1(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_01::read_lines(path).map_err(|e| e.to_string()),
+        |lines| Ok(answer!(options.integer, day_01::sum_digits(lines)?)),
+    )
+}
+
+fn day_01_part_2(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_01::read_lines(path).map_err(|e| e.to_string()),
+        |lines| {
+            Ok(answer!(
+                options.integer,
+                day_01::sum_digits_and_words(lines)?
+            ))
+        },
+    )
+}
+
+fn day_02_part_1(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_02::get_games(path),
+        |games| {
+            Ok(answer!(
+                options.integer,
+                day_02::sum_possible_ids(games, 14, 12, 13)?
+            ))
+        },
+    )
+}
+
+fn day_02_part_2(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_02::get_games(path),
+        |games| Ok(answer!(options.integer, day_02::sum_powers(games)?)),
+    )
+}
+
+fn day_03_part_1(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_03::read(path),
+        |(symbol_vec, number_vec)| {
+            Ok(answer!(
+                options.integer,
+                day_03::sum_part_numbers(symbol_vec, number_vec)?
+            ))
+        },
+    )
+}
+
+fn day_03_part_2(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_03::read(path),
+        |(symbol_vec, number_vec)| {
+            Ok(answer!(
+                options.integer,
+                day_03::sum_gear_ratios(symbol_vec, number_vec)?
+            ))
+        },
+    )
+}
+
+fn day_04_part_1(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_04::get_match_counts(path),
+        |match_counts| Ok(answer!(options.integer, day_04::sum_points(match_counts)?)),
+    )
+}
+
+fn day_04_part_2(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_04::get_match_counts(path),
+        |match_counts| Ok(answer!(options.integer, day_04::count_cards(match_counts)?)),
+    )
+}
+
+fn day_05_part_1(path: &Path, _options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_05::parse_almanac(path),
+        day_05::lowest_location,
+    )
+}
+
+fn day_05_part_2(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_05::parse_almanac(path),
+        |almanac| {
+            day_05::lowest_location_of_ranges_parallel(almanac, options.threads, options.progress)
+        },
+    )
+}
+
+fn read_bytes(path: &Path) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|e| e.to_string())
+}
+
+fn day_01_part_1_bytes(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(path, read_bytes, |input| {
+        Ok(answer!(options.integer, day_01::bytes::sum_digits(input)?))
+    })
+}
+
+fn day_01_part_2_bytes(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(path, read_bytes, |input| {
+        Ok(answer!(
+            options.integer,
+            day_01::bytes::sum_digits_and_words(input)?
+        ))
+    })
+}
+
+fn day_02_part_1_bytes(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_02::bytes::get_games(&read_bytes(path)?),
+        |games| {
+            Ok(answer!(
+                options.integer,
+                day_02::sum_possible_ids(games, 14, 12, 13)?
+            ))
+        },
+    )
+}
+
+fn day_02_part_2_bytes(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_02::bytes::get_games(&read_bytes(path)?),
+        |games| Ok(answer!(options.integer, day_02::sum_powers(games)?)),
+    )
+}
+
+fn day_03_part_1_bytes(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_03::bytes::read(&read_bytes(path)?),
+        |(symbol_vec, number_vec)| {
+            Ok(answer!(
+                options.integer,
+                day_03::sum_part_numbers(symbol_vec, number_vec)?
+            ))
+        },
+    )
+}
+
+fn day_03_part_2_bytes(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_03::bytes::read(&read_bytes(path)?),
+        |(symbol_vec, number_vec)| {
+            Ok(answer!(
+                options.integer,
+                day_03::sum_gear_ratios(symbol_vec, number_vec)?
+            ))
+        },
+    )
+}
+
+fn day_04_part_1_bytes(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_04::bytes::get_match_counts(&read_bytes(path)?),
+        |match_counts| Ok(answer!(options.integer, day_04::sum_points(match_counts)?)),
+    )
+}
+
+fn day_04_part_2_bytes(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_04::bytes::get_match_counts(&read_bytes(path)?),
+        |match_counts| Ok(answer!(options.integer, day_04::count_cards(match_counts)?)),
+    )
+}
+
+fn day_05_part_1_bytes(path: &Path, _options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_05::bytes::parse_almanac(&read_bytes(path)?),
+        day_05::lowest_location,
+    )
+}
+
+fn day_05_part_2_bytes(path: &Path, options: &RunOptions) -> Result<Solved, String> {
+    timed(
+        path,
+        |path| day_05::bytes::parse_almanac(&read_bytes(path)?),
+        |almanac| {
+            day_05::lowest_location_of_ranges_parallel(almanac, options.threads, options.progress)
+        },
+    )
+}
+
+pub static DAYS: [Day; 5] = [
+    Day {
+        number: 1,
+        directory: "day_01",
+        input: "puzzle01_input",
+        version: 1,
+        parts: [
+            Part {
+                solve: day_01_part_1,
+                solve_bytes: day_01_part_1_bytes,
+            },
+            Part {
+                solve: day_01_part_2,
+                solve_bytes: day_01_part_2_bytes,
+            },
+        ],
+        generate: day_01::gen::generate,
+    },
+    Day {
+        number: 2,
+        directory: "day_02",
+        input: "puzzle01_input",
+        version: 1,
+        parts: [
+            Part {
+                solve: day_02_part_1,
+                solve_bytes: day_02_part_1_bytes,
+            },
+            Part {
+                solve: day_02_part_2,
+                solve_bytes: day_02_part_2_bytes,
+            },
+        ],
+        generate: day_02::gen::generate,
+    },
+    Day {
+        number: 3,
+        directory: "day_03",
+        input: "puzzle01_input",
+        version: 1,
+        parts: [
+            Part {
+                solve: day_03_part_1,
+                solve_bytes: day_03_part_1_bytes,
+            },
+            Part {
+                solve: day_03_part_2,
+                solve_bytes: day_03_part_2_bytes,
+            },
+        ],
+        generate: day_03::gen::generate,
+    },
+    Day {
+        number: 4,
+        directory: "day_04",
+        input: "puzzle01_input",
+        version: 1,
+        parts: [
+            Part {
+                solve: day_04_part_1,
+                solve_bytes: day_04_part_1_bytes,
+            },
+            Part {
+                solve: day_04_part_2,
+                solve_bytes: day_04_part_2_bytes,
+            },
+        ],
+        generate: day_04::gen::generate,
+    },
+    Day {
+        number: 5,
+        directory: "day_05",
+        input: "puzzle01_input",
+        version: 1,
+        parts: [
+            Part {
+                solve: day_05_part_1,
+                solve_bytes: day_05_part_1_bytes,
+            },
+            Part {
+                solve: day_05_part_2,
+                solve_bytes: day_05_part_2_bytes,
+            },
+        ],
+        generate: day_05::gen::generate,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub fn select(number: Option<u8>) -> Result<Vec<&'static Day>, String> {
+    match number {
+        Some(number) => match find(number) {
+            Some(day) => Ok(vec![day]),
+            None => Err(format!("Day {} is not implemented", number)),
+        },
+        None => Ok(DAYS.iter().collect()),
+    }
+}
@@ -0,0 +1,66 @@
+use log::warn;
+use resvg::{tiny_skia, usvg};
+use std::{fs, path::Path};
+
+fn png(svg: &str) -> Result<Vec<u8>, String> {
+    let mut options = usvg::Options::default();
+    options.fontdb_mut().load_system_fonts();
+    if options.fontdb.is_empty() {
+        warn!("No system fonts found, the PNG is drawn without text");
+    }
+    let tree = match usvg::Tree::from_str(svg, &options) {
+        Ok(tree) => tree,
+        Err(e) => return Err(format!("Failed reading the SVG: {}", e)),
+    };
+    let size = tree.size().to_int_size();
+    let mut pixmap = match tiny_skia::Pixmap::new(size.width(), size.height()) {
+        Some(pixmap) => pixmap,
+        None => return Err(String::from("The image has no pixels")),
+    };
+    resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    match pixmap.encode_png() {
+        Ok(png) => Ok(png),
+        Err(e) => Err(format!("Failed encoding the PNG: {}", e)),
+    }
+}
+
+// Writes the SVG as is or rasterized, depending on the extension of `output`.
+pub fn write<P>(svg: &str, output: P) -> Result<(), String>
+where
+    P: AsRef<Path>,
+{
+    let output = output.as_ref();
+    let content = match output.extension().and_then(|extension| extension.to_str()) {
+        Some("svg") => svg.as_bytes().to_vec(),
+        Some("png") => png(svg)?,
+        _ => {
+            return Err(format!(
+                "Cannot tell the image format of {}, use .svg or .png",
+                output.display()
+            ))
+        }
+    };
+    match fs::write(output, content) {
+        Ok(()) => Ok(()),
+        Err(e) => Err(format!("Failed writing {}: {}", output.display(), e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{png, write};
+    use std::env;
+
+    const SVG: &str = "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"4\" height=\"3\"><rect width=\"4\" height=\"3\" fill=\"red\"/></svg>";
+
+    #[test]
+    fn svg_is_rasterized_to_png() {
+        let png = png(SVG).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+
+    #[test]
+    fn unknown_extensions_are_errors() {
+        assert!(write(SVG, env::temp_dir().join("aoc_export.bmp")).is_err());
+    }
+}
@@ -0,0 +1,329 @@
+use crate::days::workspace_root;
+use std::{fs, path::Path};
+use toml_edit::{value, ArrayOfTables, DocumentMut, Item, Table};
+
+/// What the description of one part shows: the example input and the answer
+/// for it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PartExample {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+// The text between every `open` and the following `close`, with the offset
+// where it starts.
+fn between<'a>(html: &'a str, open: &str, close: &str) -> Vec<(usize, &'a str)> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = html[offset..]
+        .find(open)
+        .map(|start| offset + start + open.len())
+    {
+        let Some(end) = html[start..].find(close).map(|end| start + end) else {
+            break;
+        };
+        found.push((start, &html[start..end]));
+        offset = end + close.len();
+    }
+    found
+}
+
+// The text of some HTML, without tags and with the entities of the puzzle
+// pages decoded.
+fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Reads the examples of a saved puzzle page, one per part description. The
+/// answer of a part is the last emphasized code in its description, its
+/// example the last code block before that answer.
+pub fn extract(html: &str) -> Vec<PartExample> {
+    let mut articles: Vec<&str> = between(html, "<article", "</article>")
+        .into_iter()
+        .map(|(_, article)| article)
+        .collect();
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    articles
+        .into_iter()
+        .map(|article| {
+            let mut answers = between(article, "<code><em>", "</em></code>");
+            answers.extend(between(article, "<em><code>", "</code></em>"));
+            let answer = answers.into_iter().max_by_key(|(start, _)| *start);
+            let end = answer.map_or(article.len(), |(start, _)| start);
+            let input = between(article, "<pre><code>", "</code></pre>")
+                .into_iter()
+                .rev()
+                .find(|(start, _)| *start < end)
+                .map(|(_, block)| text(block));
+            PartExample {
+                input,
+                answer: answer.map(|(_, answer)| text(answer)),
+            }
+        })
+        .collect()
+}
+
+/// Lists an example of a part in the content of an examples.toml, or updates
+/// the answer if the part lists `input` already. Comments and the other
+/// examples are kept.
+pub fn set_example(
+    manifest: &str,
+    part: usize,
+    input: &str,
+    answer: Option<&str>,
+) -> Result<String, String> {
+    let mut document: DocumentMut = match manifest.parse() {
+        Ok(document) => document,
+        Err(e) => return Err(format!("Failed parsing examples: {}", e)),
+    };
+    let key = format!("part{}", part);
+    let Some(examples) = document
+        .entry(&key)
+        .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
+        .as_array_of_tables_mut()
+    else {
+        return Err(format!(
+            "{} in examples.toml is not a list of examples",
+            key
+        ));
+    };
+    let listed = examples
+        .iter()
+        .position(|example| example.get("input").and_then(Item::as_str) == Some(input));
+    let example = match listed {
+        Some(i) => examples.get_mut(i).expect("position is in the list"),
+        None => {
+            let mut example = Table::new();
+            example.insert("input", value(input));
+            examples.push(example);
+            examples
+                .get_mut(examples.len() - 1)
+                .expect("pushed just now")
+        }
+    };
+    if let Some(answer) = answer {
+        // Numbers as `Answer::Number` reads them. TOML integers stop at
+        // `i64::MAX`, above that the answer is kept as text like any other.
+        match answer
+            .parse::<u64>()
+            .ok()
+            .and_then(|n| i64::try_from(n).ok())
+        {
+            Some(number) => example.insert("answer", value(number)),
+            None => example.insert("answer", value(answer)),
+        };
+    }
+    Ok(document.to_string())
+}
+
+/// The example file written for a part, relative to the workspace, and the
+/// answer found for it.
+pub struct Extracted {
+    pub file: Option<String>,
+    pub answer: Option<String>,
+}
+
+// Writes `content` unless `path` holds something else already, which only
+// `force` overwrites.
+fn write(path: &Path, content: &str, force: bool) -> Result<(), String> {
+    if let Ok(existing) = fs::read_to_string(path) {
+        if existing == content {
+            return Ok(());
+        }
+        if !force {
+            return Err(format!(
+                "{} differs from the extracted example, use --force to overwrite it",
+                path.display()
+            ));
+        }
+    }
+    match fs::write(path, content) {
+        Ok(()) => Ok(()),
+        Err(e) => Err(format!("Failed writing {}: {}", path.display(), e)),
+    }
+}
+
+// Sets the example and the answer of every part in `manifest`, and returns
+// the example files to write, by part. A part without
+// an example block of its own, like most second parts, or with the same block
+// as part 1 runs on the example of part 1.
+fn set_examples<'a>(
+    manifest: &mut String,
+    parts: &'a [PartExample],
+) -> Result<Vec<(usize, &'a str)>, String> {
+    let mut files = Vec::new();
+    for (i, part) in parts.iter().take(2).enumerate() {
+        let name = match &part.input {
+            Some(input) if i == 0 || parts[0].input.as_ref() != Some(input) => {
+                let name = format!("puzzle0{}_input_test", i + 1);
+                files.push((i + 1, input.as_str()));
+                name
+            }
+            _ if i > 0 && parts[0].input.is_some() => String::from("puzzle01_input_test"),
+            _ => continue,
+        };
+        *manifest = set_example(manifest, i + 1, &name, part.answer.as_deref())?;
+    }
+    Ok(files)
+}
+
+/// Extracts the examples of a day from its saved puzzle page into the day's
+/// example files and examples.toml. Part 1 goes to `puzzle01_input_test`,
+/// part 2 to `puzzle02_input_test` if its example differs from the one of
+/// part 1.
+pub fn extract_day(day: u8, html: &Path, force: bool) -> Result<Vec<Extracted>, String> {
+    let html = match fs::read_to_string(html) {
+        Ok(html) => html,
+        Err(e) => return Err(format!("Failed reading {}: {}", html.display(), e)),
+    };
+    let directory = format!("day_{:02}", day);
+    let root = workspace_root();
+    if !root.join(&directory).is_dir() {
+        return Err(format!(
+            "{} does not exist, create it with `aoc new {}` first",
+            directory, day
+        ));
+    }
+    let parts = extract(&html);
+    if parts.iter().all(|part| part.input.is_none()) {
+        return Err(String::from("The page has no example"));
+    }
+
+    // A day without a manifest yet gets one.
+    let manifest_path = root.join(&directory).join("examples.toml");
+    let mut manifest = fs::read_to_string(&manifest_path).unwrap_or_default();
+    let files = set_examples(&mut manifest, &parts)?;
+    let mut found: Vec<Extracted> = parts
+        .iter()
+        .take(2)
+        .map(|part| Extracted {
+            file: None,
+            answer: part.answer.clone(),
+        })
+        .collect();
+    for (part, input) in files {
+        let file = format!("{}/puzzle0{}_input_test", directory, part);
+        write(&root.join(&file), input, force)?;
+        found[part - 1].file = Some(file);
+    }
+    write(&manifest_path, &manifest, true)?;
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract, set_example, set_examples, PartExample};
+    use aoc_examples::{Answer, Examples};
+    use std::path::Path;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54634</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+<em>x</em>&lt;y&gt; &amp;
+</code></pre>
+<p>Adding these together produces <code><em>281</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn examples_and_answers() {
+        assert_eq!(
+            extract(PAGE),
+            [
+                PartExample {
+                    input: Some(String::from("1abc2\npqr3stu8vwx\n")),
+                    answer: Some(String::from("142")),
+                },
+                PartExample {
+                    input: Some(String::from("two1nine\nx<y> &\n")),
+                    answer: Some(String::from("281")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn second_part_reuses_the_first_example() {
+        let page = r#"<main>
+<article class="day-desc"><h2>--- Day 4: Scratchcards ---</h2>
+<pre><code>Card 1: 41 48 | 83 86
+</code></pre>
+<p>So, in this example, the cards are worth <code><em>13</em></code> points.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>You end up with <code><em>30</em></code> scratchcards.</p>
+</article>
+</main>"#;
+        let parts = extract(page);
+        assert_eq!(parts[1].input, None);
+        let mut manifest = String::new();
+        let files = set_examples(&mut manifest, &parts).unwrap();
+        assert_eq!(files, [(1, "Card 1: 41 48 | 83 86\n")]);
+        let examples = Examples::parse(&manifest, "").unwrap();
+        for (part, answer) in [(1, 13), (2, 30)] {
+            let example = &examples.part(part)[0];
+            assert_eq!(example.input, Path::new("puzzle01_input_test"));
+            assert_eq!(example.answer, Some(Answer::Number(answer)));
+        }
+    }
+
+    #[test]
+    fn examples_keep_their_layout() {
+        let manifest = "# Examples\n\n[[part1]]\ninput = \"puzzle01_input_test\"\nanswer = 1\n";
+        let manifest = set_example(manifest, 1, "puzzle01_input_test", Some("142")).unwrap();
+        let manifest = set_example(&manifest, 2, "puzzle02_input_test", Some("abc")).unwrap();
+        let manifest = set_example(&manifest, 1, "puzzle03_input_test", None).unwrap();
+        assert_eq!(
+            manifest,
+            "# Examples\n\n[[part1]]\ninput = \"puzzle01_input_test\"\nanswer = 142\n\n[[part1]]\ninput = \"puzzle03_input_test\"\n\n[[part2]]\ninput = \"puzzle02_input_test\"\nanswer = \"abc\"\n"
+        );
+    }
+
+    #[test]
+    fn answers_read_back_as_written() {
+        let manifest = set_example("", 1, "a", Some("18446744073709551615")).unwrap();
+        let manifest = set_example(&manifest, 1, "b", Some("9223372036854775807")).unwrap();
+        let manifest = set_example(&manifest, 2, "c", Some("-3")).unwrap();
+        let examples = Examples::parse(&manifest, "").unwrap();
+        let answers = |part| {
+            examples
+                .part(part)
+                .iter()
+                .map(|example| example.answer.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            answers(1),
+            [
+                Some(Answer::Text(String::from("18446744073709551615"))),
+                Some(Answer::Number(9223372036854775807)),
+            ]
+        );
+        assert_eq!(answers(2), [Some(Answer::Text(String::from("-3")))]);
+    }
+}
@@ -0,0 +1,613 @@
+mod answers;
+mod bench;
+mod cache;
+mod days;
+mod export;
+mod extract;
+mod repl;
+mod report;
+mod scaffold;
+mod timing;
+mod verify;
+mod watch;
+
+use answers::Answers;
+use cache::Cache;
+use clap::{ArgAction, Parser, Subcommand};
+use days::{Integer, Reader, RunOptions};
+use log::{warn, LevelFilter};
+use report::{Format, Record};
+use std::{
+    env, fs,
+    io::{self, IsTerminal},
+    path::PathBuf,
+    process::ExitCode,
+    time::Duration,
+};
+use timing::format_duration;
+use verify::{InputKind, Status};
+
+#[derive(Parser)]
+#[command(about = "Runner for the Advent of Code 2023 solutions")]
+struct Cli {
+    /// Threads for solvers that run in parallel, 0 for one per CPU
+    #[arg(long, global = true, default_value_t = 0)]
+    threads: usize,
+    /// How solvers read their input
+    #[arg(long, global = true, value_enum, default_value_t = Reader::Lines)]
+    reader: Reader,
+    /// Show the progress of long running solvers, like day 5 part 2, on
+    /// stderr
+    #[arg(long, global = true)]
+    progress: bool,
+    /// Integer type days 1 to 4 sum their answers into. Fixed widths are
+    /// faster, but an answer that outgrows them is an error
+    #[arg(long = "int", global = true, value_enum, default_value_t = Integer::Big)]
+    integer: Integer,
+    /// Log diagnostics to stderr, repeat for more detail. RUST_LOG overrides
+    /// the level
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve every day and report parse and solve times
+    Run {
+        /// Only run this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Run the examples of examples.toml and check their answers
+        #[arg(long)]
+        examples: bool,
+        /// Solve the day on this input, e.g. one written by `gen`, instead
+        /// of its real input. Its answers are not cached
+        #[arg(long, requires = "day", conflicts_with = "examples")]
+        input: Option<PathBuf>,
+        /// Output format of the results
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Solve every part again instead of using the answers cached by
+        /// earlier runs, and cache the new answers
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Run every day against answers.toml and the examples.toml of each day
+    Verify {
+        /// Only verify this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only verify the example inputs
+        #[arg(long)]
+        examples_only: bool,
+        /// Output format of the results
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Time repeated runs and report mean, median and standard deviation
+    Bench {
+        /// Only benchmark this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Only benchmark this part
+        #[arg(long)]
+        part: Option<usize>,
+        /// Use the first example of each part instead of the real input
+        #[arg(long)]
+        examples: bool,
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Timed runs
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Write the results as JSON to this file
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Generate a random input for a day
+    Gen {
+        day: u8,
+        /// Size of the input, e.g. the number of lines or the grid width
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+        /// Seed of the random number generator
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Longest match chain of the scratchcards of day 4: every card wins
+        /// up to this many of the cards after it
+        #[arg(long)]
+        matches: Option<usize>,
+        /// Write the input to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Create the crate of a new day and register it with the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Answer of part 1 for the example, if known
+        #[arg(long)]
+        example1: Option<u64>,
+        /// Answer of part 2 for the example, if known
+        #[arg(long)]
+        example2: Option<u64>,
+    },
+    /// Write the examples and their answers from a saved puzzle page
+    Extract {
+        day: u8,
+        /// The puzzle page saved as HTML
+        html: PathBuf,
+        /// Overwrite example files that differ from the page
+        #[arg(long)]
+        force: bool,
+    },
+    /// Parse the input of a day once and explore it with commands
+    Repl {
+        day: u8,
+        /// Use the example input instead of the real one
+        #[arg(long)]
+        examples: bool,
+    },
+    /// Run the examples and then the input of a day whenever its sources,
+    /// inputs or examples change
+    Watch { day: u8 },
+    /// Tools for day 3
+    Day03 {
+        #[command(subcommand)]
+        command: Day03Command,
+    },
+    /// Tools for day 5
+    Day05 {
+        #[command(subcommand)]
+        command: Day05Command,
+    },
+}
+
+#[derive(Subcommand)]
+enum Day03Command {
+    /// Print the schematic with part numbers, other numbers and gears marked
+    Render {
+        /// Use the example input instead of the real one
+        #[arg(long)]
+        examples: bool,
+        /// Print without colours even on a terminal
+        #[arg(long)]
+        no_colour: bool,
+    },
+    /// Draw the schematic with part numbers and gears marked to an SVG or PNG
+    Export {
+        /// Image file to write, .svg or .png
+        output: PathBuf,
+        /// Use the example input instead of the real one
+        #[arg(long)]
+        examples: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum Day05Command {
+    /// Follow a seed through every map of the almanac to its location
+    Trace {
+        seed: u64,
+        /// Use the example input instead of the real one
+        #[arg(long)]
+        examples: bool,
+    },
+    /// Draw every map as source and destination intervals to an SVG or PNG
+    Export {
+        /// Image file to write, .svg or .png
+        output: PathBuf,
+        /// Use the example input instead of the real one
+        #[arg(long)]
+        examples: bool,
+    },
+}
+
+// The real input, or the first example of the part.
+fn input_path(day: &days::Day, part: usize, examples: bool) -> Result<PathBuf, String> {
+    if !examples {
+        return Ok(day.input_path());
+    }
+    match day.examples()?.part(part).first() {
+        Some(example) => Ok(example.input.clone()),
+        None => Err(format!(
+            "Day {} part {} has no examples in {}/examples.toml",
+            day.number, part, day.directory
+        )),
+    }
+}
+
+// Answers of the real inputs come from the cache unless `no_cache` is set,
+// the examples are always solved.
+fn run(
+    day: Option<u8>,
+    examples: bool,
+    input: Option<PathBuf>,
+    format: Format,
+    no_cache: bool,
+    options: &RunOptions,
+) -> Result<bool, String> {
+    let days = days::select(day)?;
+    if examples {
+        let checks = verify::verify(days, &[InputKind::Example], &Answers::default(), options);
+        return print_checks(&checks, format);
+    }
+    let mut cache = Cache::load(&cache::path());
+    let mut records = Vec::new();
+    for day in days {
+        for part in 1..=day.parts.len() {
+            // The cache keeps one answer per part, that of the real input.
+            let (path, solved) = match &input {
+                Some(path) => (
+                    path.clone(),
+                    day.parts[part - 1]
+                        .run(path, options)
+                        .map(|solved| (solved, false)),
+                ),
+                None => {
+                    let path = day.input_path();
+                    let solved = cache.solve(day, part, &path, no_cache, options);
+                    (path, solved)
+                }
+            };
+            let cached = matches!(solved, Ok((_, true)));
+            let mut record = Record::new(
+                day.number,
+                part,
+                InputKind::Input,
+                &path,
+                solved.map(|(solved, _)| (solved.answer, solved.parse, solved.solve)),
+            );
+            record.cached = cached;
+            records.push(record);
+        }
+    }
+    if let Err(e) = cache.save(&cache::path()) {
+        warn!("{}", e);
+    }
+    match format {
+        Format::Text => print_run_table(&records),
+        Format::Json => report::print_json(&records)?,
+        Format::Csv => report::write_csv(&records, io::stdout())?,
+    }
+    Ok(records.iter().all(|record| record.error.is_none()))
+}
+
+fn print_run_table(records: &[Record]) {
+    println!(
+        "{:>3}  {:>4}  {:>16}  {:>10}  {:>10}",
+        "Day", "Part", "Answer", "Parse", "Solve"
+    );
+    let seconds = |seconds: Option<f64>| match seconds {
+        Some(seconds) => format_duration(Duration::from_secs_f64(seconds)),
+        None => String::from("-"),
+    };
+    for record in records {
+        print!(
+            "{:>3}  {:>4}  {:>16}  {:>10}  {:>10}",
+            record.day,
+            record.part,
+            record.answer.as_deref().unwrap_or("-"),
+            seconds(record.parse),
+            seconds(record.solve)
+        );
+        match &record.error {
+            Some(e) => println!("  ERROR: {}", e),
+            None if record.cached => println!("  cached"),
+            None => println!(),
+        }
+    }
+}
+
+fn verify(
+    day: Option<u8>,
+    examples_only: bool,
+    format: Format,
+    options: &RunOptions,
+) -> Result<bool, String> {
+    let answers = Answers::load(days::workspace_root().join("answers.toml"))?;
+    let kinds = if examples_only {
+        vec![InputKind::Example]
+    } else {
+        vec![InputKind::Example, InputKind::Input]
+    };
+    let checks = verify::verify(days::select(day)?, &kinds, &answers, options);
+    print_checks(&checks, format)
+}
+
+fn print_checks(checks: &[verify::Check], format: Format) -> Result<bool, String> {
+    let records = || {
+        checks
+            .iter()
+            .map(Record::from_check)
+            .collect::<Vec<Record>>()
+    };
+    match format {
+        Format::Text => verify::print_table(checks),
+        Format::Json => report::print_json(&records())?,
+        Format::Csv => report::write_csv(&records(), io::stdout())?,
+    }
+    Ok(!checks
+        .iter()
+        .any(|check| matches!(check.status, Status::Fail | Status::Error(_))))
+}
+
+fn bench(
+    day: Option<u8>,
+    part: Option<usize>,
+    examples: bool,
+    warmup: usize,
+    runs: usize,
+    output: Option<PathBuf>,
+    options: &RunOptions,
+) -> Result<(), String> {
+    let mut results = Vec::new();
+    for day in days::select(day)? {
+        for p in 1..=day.parts.len() {
+            if part.is_some_and(|part| part != p) {
+                continue;
+            }
+            results.push(bench::bench(
+                day,
+                p,
+                &input_path(day, p, examples)?,
+                warmup,
+                runs,
+                options,
+            )?);
+        }
+    }
+    bench::print_table(&results);
+    match output {
+        Some(output) => bench::write_json(&results, output),
+        None => Ok(()),
+    }
+}
+
+fn new_day(day: u8, example1: Option<u64>, example2: Option<u64>) -> Result<(), String> {
+    scaffold::new_day(day, [example1, example2])?;
+    let directory = format!("day_{:02}", day);
+    println!(
+        "Created {}, registered with the workspace and the runner.",
+        directory
+    );
+    println!(
+        "Paste the example into {0}/puzzle01_input_test and the input into {0}/puzzle01_input.",
+        directory
+    );
+    println!(
+        "List further examples and their answers in {}/examples.toml.",
+        directory
+    );
+    Ok(())
+}
+
+fn extract(day: u8, html: PathBuf, force: bool) -> Result<(), String> {
+    let found = extract::extract_day(day, &html, force)?;
+    for (i, extracted) in found.iter().enumerate() {
+        let part = i + 1;
+        match &extracted.file {
+            Some(file) => println!("Part {}: example written to {}", part, file),
+            None => println!("Part {}: same example as part 1", part),
+        }
+        match &extracted.answer {
+            Some(answer) => println!("Part {}: example answer {}", part, answer),
+            None => println!("Part {}: no example answer found", part),
+        }
+    }
+    Ok(())
+}
+
+fn repl(day: u8, examples: bool) -> Result<(), String> {
+    let Some(found) = days::find(day) else {
+        return Err(format!("Day {} is not implemented", day));
+    };
+    repl::repl(day, &input_path(found, 1, examples)?)
+}
+
+fn watch(day: u8, options: &RunOptions) -> Result<(), String> {
+    let day = match days::find(day) {
+        Some(day) => day,
+        None => return Err(format!("Day {} is not implemented", day)),
+    };
+    let reader = match options.reader {
+        Reader::Lines => "lines",
+        Reader::Bytes => "bytes",
+    };
+    let integer = match options.integer {
+        Integer::U32 => "u32",
+        Integer::U64 => "u64",
+        Integer::U128 => "u128",
+        Integer::Big => "big",
+    };
+    let options = [
+        String::from("--threads"),
+        options.threads.to_string(),
+        String::from("--reader"),
+        String::from(reader),
+        String::from("--int"),
+        String::from(integer),
+    ];
+    watch::watch(day, &options)
+}
+
+fn generate(
+    day: u8,
+    size: usize,
+    seed: u64,
+    matches: Option<usize>,
+    output: Option<PathBuf>,
+) -> Result<(), String> {
+    let day = match days::find(day) {
+        Some(day) => day,
+        None => return Err(format!("Day {} is not implemented", day)),
+    };
+    let input = match matches {
+        None => (day.generate)(size, seed),
+        Some(matches) if day.number == 4 => day_04::gen::generate_with_matches(size, seed, matches),
+        Some(_) => return Err(String::from("--matches only applies to day 4")),
+    };
+    match output {
+        Some(output) => match fs::write(&output, input) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(format!("Failed writing {}: {}", output.display(), e)),
+        },
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+struct Schematic {
+    symbol_vec: Vec<day_03::Symbol>,
+    number_vec: Vec<day_03::Number>,
+    width: usize,
+    height: usize,
+}
+
+fn read_schematic(examples: bool) -> Result<Schematic, String> {
+    let day = days::find(3).ok_or("Day 3 is not implemented")?;
+    let path = input_path(day, 1, examples)?;
+    let input = match fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(e) => return Err(format!("Failed reading {}: {}", path.display(), e)),
+    };
+    let (symbol_vec, number_vec) = day_03::read_from_reader(input.as_bytes())?;
+    Ok(Schematic {
+        symbol_vec,
+        number_vec,
+        width: input.lines().map(str::len).max().unwrap_or(0),
+        height: input.lines().count(),
+    })
+}
+
+fn render(examples: bool, no_colour: bool) -> Result<(), String> {
+    let schematic = read_schematic(examples)?;
+    let colour = !no_colour && env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal();
+    print!(
+        "{}",
+        day_03::render::render(
+            &schematic.symbol_vec,
+            &schematic.number_vec,
+            schematic.width,
+            schematic.height,
+            colour
+        )
+    );
+    Ok(())
+}
+
+fn export_schematic(output: PathBuf, examples: bool) -> Result<(), String> {
+    let schematic = read_schematic(examples)?;
+    let svg = day_03::render::svg(
+        &schematic.symbol_vec,
+        &schematic.number_vec,
+        schematic.width,
+        schematic.height,
+    );
+    export::write(&svg, output)
+}
+
+fn export_almanac(output: PathBuf, examples: bool) -> Result<(), String> {
+    let day = days::find(5).ok_or("Day 5 is not implemented")?;
+    let almanac = day_05::parse_almanac(input_path(day, 1, examples)?)?;
+    export::write(&day_05::diagram::svg(&almanac), output)
+}
+
+fn trace(seed: u64, examples: bool) -> Result<(), String> {
+    let day = days::find(5).ok_or("Day 5 is not implemented")?;
+    let almanac = day_05::parse_almanac(input_path(day, 1, examples)?)?;
+    println!("seed {}", seed);
+    for step in almanac.trace(seed)? {
+        println!("  {}", step);
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let level = match cli.verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    // The SVG renderer warns once per text element when fonts are missing,
+    // which `export::write` reports once already.
+    env_logger::Builder::new()
+        .filter_level(level)
+        .filter_module("usvg", LevelFilter::Error)
+        .parse_default_env()
+        .init();
+    let options = RunOptions {
+        threads: cli.threads,
+        reader: cli.reader,
+        progress: cli.progress,
+        integer: cli.integer,
+    };
+    let result = match cli.command {
+        Command::Run {
+            day,
+            examples,
+            input,
+            format,
+            no_cache,
+        } => run(day, examples, input, format, no_cache, &options),
+        Command::Verify {
+            day,
+            examples_only,
+            format,
+        } => verify(day, examples_only, format, &options),
+        Command::Bench {
+            day,
+            part,
+            examples,
+            warmup,
+            runs,
+            output,
+        } => bench(day, part, examples, warmup, runs, output, &options).map(|()| true),
+        Command::Gen {
+            day,
+            size,
+            seed,
+            matches,
+            output,
+        } => generate(day, size, seed, matches, output).map(|()| true),
+        Command::New {
+            day,
+            example1,
+            example2,
+        } => new_day(day, example1, example2).map(|()| true),
+        Command::Extract { day, html, force } => extract(day, html, force).map(|()| true),
+        Command::Repl { day, examples } => repl(day, examples).map(|()| true),
+        Command::Watch { day } => watch(day, &options).map(|()| true),
+        Command::Day03 {
+            command:
+                Day03Command::Render {
+                    examples,
+                    no_colour,
+                },
+        } => render(examples, no_colour).map(|()| true),
+        Command::Day03 {
+            command: Day03Command::Export { output, examples },
+        } => export_schematic(output, examples).map(|()| true),
+        Command::Day05 {
+            command: Day05Command::Trace { seed, examples },
+        } => trace(seed, examples).map(|()| true),
+        Command::Day05 {
+            command: Day05Command::Export { output, examples },
+        } => export_almanac(output, examples).map(|()| true),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,386 @@
+use day_02::Game;
+use day_03::{Number, Symbol};
+use day_05::Almanac;
+use std::{
+    io::{self, BufRead, IsTerminal, Write},
+    path::Path,
+    str::FromStr,
+};
+
+/// The parsed input of a day, answering the commands of the REPL.
+trait Model {
+    /// Usage and description of every command.
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    fn eval(&self, command: &str, args: &[&str]) -> Result<String, String>;
+}
+
+// The argument at `index`, named after its place in the usage.
+fn arg<T>(args: &[&str], index: usize, name: &str) -> Result<T, String>
+where
+    T: FromStr,
+{
+    match args.get(index).map(|arg| arg.parse()) {
+        Some(Ok(value)) => Ok(value),
+        Some(Err(_)) => Err(format!("<{}> is not valid: {}", name, args[index])),
+        None => Err(format!("<{}> is missing", name)),
+    }
+}
+
+fn unknown(command: &str) -> Result<String, String> {
+    Err(format!("Unknown command {}, try help", command))
+}
+
+struct Calibration(Vec<String>);
+
+impl Model for Calibration {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[(
+            "line <n>",
+            "The calibration value of line n, words included",
+        )]
+    }
+
+    fn eval(&self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "line" => {
+                let n: usize = arg(args, 0, "n")?;
+                let Some(line) = n.checked_sub(1).and_then(|i| self.0.get(i)) else {
+                    return Err(format!("There are {} lines", self.0.len()));
+                };
+                let value = day_01::first_and_last_digit(line)?;
+                Ok(format!("{}: {}", line, value))
+            }
+            _ => unknown(command),
+        }
+    }
+}
+
+struct Games(Vec<Game>);
+
+impl Games {
+    fn game(&self, id: u32) -> Result<&Game, String> {
+        match self.0.iter().find(|game| game.id() == id) {
+            Some(game) => Ok(game),
+            None => Err(format!("There is no game {}", id)),
+        }
+    }
+}
+
+impl Model for Games {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("game <id>", "The most cubes of each colour the game shows"),
+            ("power <id>", "The power of the fewest cubes the game needs"),
+            (
+                "possible <red> <green> <blue>",
+                "The games possible with that many cubes",
+            ),
+        ]
+    }
+
+    fn eval(&self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "game" => Ok(self.game(arg(args, 0, "id")?)?.to_string()),
+            // Three u32 cube counts multiply to less than u128::MAX.
+            "power" => match self.game(arg(args, 0, "id")?)?.power::<u128>() {
+                Some(power) => Ok(power.to_string()),
+                None => Err(String::from("The power overflows u128")),
+            },
+            "possible" => {
+                let red = arg(args, 0, "red")?;
+                let green = arg(args, 1, "green")?;
+                let blue = arg(args, 2, "blue")?;
+                let ids: Vec<String> = self
+                    .0
+                    .iter()
+                    .filter(|game| game.is_possible(blue, red, green))
+                    .map(|game| game.id().to_string())
+                    .collect();
+                Ok(format!(
+                    "{} of {} games: {}",
+                    ids.len(),
+                    self.0.len(),
+                    ids.join(", ")
+                ))
+            }
+            _ => unknown(command),
+        }
+    }
+}
+
+struct Schematic {
+    symbol_vec: Vec<Symbol>,
+    number_vec: Vec<Number>,
+}
+
+impl Schematic {
+    fn number_at(&self, coordinate: (usize, usize)) -> Option<&Number> {
+        self.number_vec
+            .iter()
+            .find(|number| number.coordinates.contains(&coordinate))
+    }
+
+    fn symbol_at(&self, coordinate: (usize, usize)) -> Option<&Symbol> {
+        self.symbol_vec
+            .iter()
+            .find(|symbol| symbol.coordinate == coordinate)
+    }
+}
+
+impl Model for Schematic {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            (
+                "neighbours <x> <y>",
+                "The symbols and numbers around a column and row, counted from 0",
+            ),
+            (
+                "number <x> <y>",
+                "The number at a column and row and the symbols it touches",
+            ),
+        ]
+    }
+
+    fn eval(&self, command: &str, args: &[&str]) -> Result<String, String> {
+        let coordinate =
+            || -> Result<(usize, usize), String> { Ok((arg(args, 0, "x")?, arg(args, 1, "y")?)) };
+        match command {
+            "neighbours" => {
+                let coordinate = coordinate()?;
+                let mut found = Vec::new();
+                let mut numbers: Vec<&Number> = Vec::new();
+                for neighbour in day_03::get_neighbours(&[coordinate]) {
+                    if neighbour == coordinate {
+                        continue;
+                    }
+                    if let Some(symbol) = self.symbol_at(neighbour) {
+                        found.push(format!("{:?}: {}", neighbour, symbol.symbol));
+                    }
+                    // A number spans several neighbours but is listed once.
+                    if let Some(number) = self.number_at(neighbour) {
+                        if !numbers.iter().any(|n| n.coordinates == number.coordinates) {
+                            found.push(format!("{:?}: {}", number.coordinates[0], number.value));
+                            numbers.push(number);
+                        }
+                    }
+                }
+                if found.is_empty() {
+                    Ok(String::from("Nothing around"))
+                } else {
+                    Ok(found.join("\n"))
+                }
+            }
+            "number" => {
+                let coordinate = coordinate()?;
+                let Some(number) = self.number_at(coordinate) else {
+                    return Err(format!("There is no number at {:?}", coordinate));
+                };
+                let symbols: Vec<String> = day_03::get_neighbours(&number.coordinates)
+                    .into_iter()
+                    .filter_map(|neighbour| self.symbol_at(neighbour))
+                    .map(|symbol| format!("{} at {:?}", symbol.symbol, symbol.coordinate))
+                    .collect();
+                if symbols.is_empty() {
+                    Ok(format!("{}, not a part number", number.value))
+                } else {
+                    Ok(format!(
+                        "{}, a part number next to {}",
+                        number.value,
+                        symbols.join(", ")
+                    ))
+                }
+            }
+            _ => unknown(command),
+        }
+    }
+}
+
+struct Cards(Vec<u32>);
+
+impl Model for Cards {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("card <n>", "The matches of card n and its points"),
+            (
+                "cards <op> <matches>",
+                "The number of cards whose matches compare, op is one of < <= = >= >",
+            ),
+        ]
+    }
+
+    fn eval(&self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "card" => {
+                let n: usize = arg(args, 0, "n")?;
+                let Some(matches) = n.checked_sub(1).and_then(|i| self.0.get(i)) else {
+                    return Err(format!("There are {} cards", self.0.len()));
+                };
+                Ok(format!(
+                    "Card {}: {} matches, {} points",
+                    n,
+                    matches,
+                    day_04::sum_points::<u64>(&[*matches])?
+                ))
+            }
+            "cards" => {
+                let op: String = arg(args, 0, "op")?;
+                let matches: u32 = arg(args, 1, "matches")?;
+                let compare: fn(&u32, &u32) -> bool = match op.as_str() {
+                    "<" => u32::lt,
+                    "<=" => u32::le,
+                    "=" | "==" => u32::eq,
+                    ">=" => u32::ge,
+                    ">" => u32::gt,
+                    _ => return Err(format!("<op> is not valid: {}", op)),
+                };
+                let count = self.0.iter().filter(|m| compare(m, &matches)).count();
+                Ok(format!("{} of {} cards", count, self.0.len()))
+            }
+            _ => unknown(command),
+        }
+    }
+}
+
+impl Model for Almanac {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            (
+                "map <seed>",
+                "Every map the seed goes through to its location",
+            ),
+            ("location <seed>", "The location of the seed"),
+            ("seeds <location>", "The seeds that end up at the location"),
+        ]
+    }
+
+    fn eval(&self, command: &str, args: &[&str]) -> Result<String, String> {
+        match command {
+            "map" => {
+                let seed = arg(args, 0, "seed")?;
+                let mut lines = vec![format!("seed {}", seed)];
+                for step in self.trace(seed)? {
+                    lines.push(format!("  {}", step));
+                }
+                Ok(lines.join("\n"))
+            }
+            "location" => Ok(self.location(arg(args, 0, "seed")?)?.to_string()),
+            "seeds" => {
+                let seeds = self.seeds_of_location(arg(args, 0, "location")?);
+                let seeds: Vec<String> = seeds.iter().map(u64::to_string).collect();
+                Ok(format!("{} seeds: {}", seeds.len(), seeds.join(", ")))
+            }
+            _ => unknown(command),
+        }
+    }
+}
+
+fn load(day: u8, path: &Path) -> Result<Box<dyn Model>, String> {
+    match day {
+        1 => match day_01::read_lines(path) {
+            Ok(lines) => Ok(Box::new(Calibration(lines))),
+            Err(e) => Err(format!("Failed reading {}: {}", path.display(), e)),
+        },
+        2 => Ok(Box::new(Games(day_02::get_games(path)?))),
+        3 => {
+            let (symbol_vec, number_vec) = day_03::read(path)?;
+            Ok(Box::new(Schematic {
+                symbol_vec,
+                number_vec,
+            }))
+        }
+        4 => match day_04::get_match_counts(path) {
+            Ok(match_counts) => Ok(Box::new(Cards(match_counts))),
+            Err(e) => Err(format!("Failed reading {}: {}", path.display(), e)),
+        },
+        5 => Ok(Box::new(day_05::parse_almanac(path)?)),
+        _ => Err(format!("Day {} has no REPL", day)),
+    }
+}
+
+// Runs one line of input, None if it ends the session.
+fn eval_line(model: &dyn Model, line: &str) -> Option<String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((command, args)) = words.split_first() else {
+        return Some(String::new());
+    };
+    match *command {
+        "quit" | "exit" => None,
+        "help" => {
+            let mut help: Vec<String> = model
+                .commands()
+                .iter()
+                .map(|(usage, description)| format!("{:<30} {}", usage, description))
+                .collect();
+            help.push(format!("{:<30} {}", "quit", "Leave the REPL"));
+            Some(help.join("\n"))
+        }
+        _ => match model.eval(command, args) {
+            Ok(output) => Some(output),
+            Err(e) => Some(format!("error: {}", e)),
+        },
+    }
+}
+
+/// Parses the input of a day once and answers commands about it read from
+/// stdin, until `quit` or the end of the input.
+pub fn repl(day: u8, path: &Path) -> Result<(), String> {
+    let model = load(day, path)?;
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        println!("Parsed {}, type help for the commands", path.display());
+    }
+    let mut stdin = io::stdin().lock();
+    let mut line = String::new();
+    loop {
+        if interactive {
+            print!("day{:02}> ", day);
+            // The prompt has no newline, it only shows once flushed.
+            let _ = io::stdout().flush();
+        }
+        line.clear();
+        match stdin.read_line(&mut line) {
+            Ok(0) => return Ok(()),
+            Ok(_) => {}
+            Err(e) => return Err(format!("Failed reading a command: {}", e)),
+        }
+        match eval_line(model.as_ref(), &line) {
+            Some(output) if output.is_empty() => {}
+            Some(output) => println!("{}", output),
+            None => return Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{eval_line, load};
+    use crate::days::workspace_root;
+
+    fn eval(day: u8, file: &str, line: &str) -> String {
+        let path = workspace_root().join(format!("day_{:02}", day)).join(file);
+        let model = load(day, &path).unwrap();
+        eval_line(model.as_ref(), line).unwrap()
+    }
+
+    #[test]
+    fn commands_on_the_examples() {
+        assert_eq!(
+            eval(2, "puzzle01_input_test", "game 3"),
+            "Game 3: at most 20 red, 13 green, 6 blue"
+        );
+        assert_eq!(
+            eval(2, "puzzle01_input_test", "possible 12 13 14"),
+            "3 of 5 games: 1, 2, 5"
+        );
+        assert_eq!(
+            eval(3, "puzzle01_input_test", "number 0 0"),
+            "467, a part number next to * at (3, 1)"
+        );
+        assert_eq!(eval(4, "puzzle01_input_test", "cards >= 2"), "3 of 6 cards");
+        assert!(
+            eval(5, "puzzle01_input_test", "map 79").ends_with("location 82 (entry 1: 60 56 37)")
+        );
+        assert!(eval(5, "puzzle01_input_test", "seeds").starts_with("error: <location> is missing"));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::verify::{Check, InputKind, Status};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::{io::Write, path::Path, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Aligned table for reading in a terminal
+    Text,
+    /// One JSON array of results
+    Json,
+    /// One CSV row per result with a header row
+    Csv,
+}
+
+/// One solved part in the machine-readable formats. Times are in seconds.
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: usize,
+    pub kind: String,
+    pub input: String,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub parse: Option<f64>,
+    pub solve: Option<f64>,
+    pub status: &'static str,
+    pub error: Option<String>,
+    /// Whether the answer and times come from the answer cache of `run`.
+    pub cached: bool,
+}
+
+impl Record {
+    pub fn new(
+        day: u8,
+        part: usize,
+        kind: InputKind,
+        path: &Path,
+        solved: Result<(String, Duration, Duration), String>,
+    ) -> Record {
+        let (answer, parse, solve, status, error) = match solved {
+            Ok((answer, parse, solve)) => (Some(answer), Some(parse), Some(solve), "ok", None),
+            Err(e) => (None, None, None, "error", Some(e)),
+        };
+        Record {
+            day,
+            part,
+            kind: kind.to_string(),
+            input: path.display().to_string(),
+            answer,
+            expected: None,
+            parse: parse.map(|parse| parse.as_secs_f64()),
+            solve: solve.map(|solve| solve.as_secs_f64()),
+            status,
+            error,
+            cached: false,
+        }
+    }
+
+    pub fn from_check(check: &Check) -> Record {
+        let (status, error) = match &check.status {
+            Status::Pass => ("pass", None),
+            Status::Fail => ("fail", None),
+            Status::Error(e) => ("error", Some(e.clone())),
+            Status::Missing => ("missing", None),
+        };
+        Record {
+            day: check.day,
+            part: check.part,
+            kind: check.kind.to_string(),
+            input: check.path.display().to_string(),
+            answer: check.actual.clone(),
+            expected: check.expected.as_ref().map(|answer| answer.to_string()),
+            parse: check.parse.map(|parse| parse.as_secs_f64()),
+            solve: check.solve.map(|solve| solve.as_secs_f64()),
+            status,
+            error,
+            cached: false,
+        }
+    }
+}
+
+pub fn print_json(records: &[Record]) -> Result<(), String> {
+    match serde_json::to_string_pretty(records) {
+        Ok(json) => {
+            println!("{}", json);
+            Ok(())
+        }
+        Err(e) => Err(format!("Failed serializing results: {}", e)),
+    }
+}
+
+/// Writes a header row and one row per record to `output`.
+pub fn write_csv(records: &[Record], output: impl Write) -> Result<(), String> {
+    let mut writer = csv::Writer::from_writer(output);
+    for record in records {
+        if let Err(e) = writer.serialize(record) {
+            return Err(format!("Failed writing results: {}", e));
+        }
+    }
+    match writer.flush() {
+        Ok(()) => Ok(()),
+        Err(e) => Err(format!("Failed writing results: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_csv, Record};
+    use crate::verify::InputKind;
+    use std::{path::Path, time::Duration};
+
+    #[test]
+    fn csv_has_a_header_and_a_row_per_record() {
+        let records = vec![
+            Record::new(
+                1,
+                2,
+                InputKind::Example,
+                Path::new("day_01/example"),
+                Ok((
+                    String::from("281"),
+                    Duration::from_millis(2),
+                    Duration::ZERO,
+                )),
+            ),
+            Record::new(
+                5,
+                1,
+                InputKind::Input,
+                Path::new("day_05/input, generated"),
+                Err(String::from("Error opening file, maybe")),
+            ),
+        ];
+        let mut csv = Vec::new();
+        write_csv(&records, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(
+            csv,
+            "day,part,kind,input,answer,expected,parse,solve,status,error,cached\n\
+             1,2,example,day_01/example,281,,0.002,0.0,ok,,false\n\
+             5,1,input,\"day_05/input, generated\",,,,,error,\"Error opening file, maybe\",false\n"
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use crate::days::{self, workspace_root};
+use log::info;
+use std::{fs, path::Path};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const MAIN_RS: &str = include_str!("../templates/main.rs.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs.in");
+const GEN_RS: &str = include_str!("../templates/gen.rs.in");
+
+// The examples.toml of a new day. Both parts start on the same example, and
+// a part whose answer is not known yet only runs it.
+fn examples_manifest(answers: [Option<u64>; 2]) -> String {
+    let mut manifest = String::from(
+        "# Examples of the puzzle with their answers, checked by the tests and by\n# `cargo run -p aoc -- verify`.\n",
+    );
+    for (i, answer) in answers.iter().enumerate() {
+        manifest.push_str(&format!(
+            "\n[[part{}]]\ninput = \"puzzle01_input_test\"\n",
+            i + 1
+        ));
+        if let Some(answer) = answer {
+            manifest.push_str(&format!("answer = {}\n", answer));
+        }
+    }
+    manifest
+}
+
+// Adds `name` to the members of the workspace manifest.
+fn add_member(manifest: &str, name: &str) -> Result<String, String> {
+    let Some(start) = manifest.find("members = [") else {
+        return Err(String::from("The workspace manifest has no members list"));
+    };
+    let Some(end) = manifest[start..].find(']').map(|end| start + end) else {
+        return Err(String::from(
+            "The members list of the workspace is not closed",
+        ));
+    };
+    Ok(format!(
+        "{}, \"{}\"{}",
+        &manifest[..end],
+        name,
+        &manifest[end..]
+    ))
+}
+
+// Adds the new day as a dependency of the runner, after the other days.
+fn add_dependency(manifest: &str, name: &str) -> Result<String, String> {
+    let Some(last_day) = manifest.rfind("\nday_") else {
+        return Err(String::from("The runner does not depend on any day"));
+    };
+    let end = match manifest[last_day + 1..].find('\n') {
+        Some(end) => last_day + 1 + end,
+        None => manifest.len(),
+    };
+    Ok(format!(
+        "{}\n{} = {{ path = \"../{}\" }}{}",
+        &manifest[..end],
+        name,
+        name,
+        &manifest[end..]
+    ))
+}
+
+// Adds the solvers of the new day and its entry in `DAYS` to days.rs.
+fn register_day(source: &str, number: u8) -> Result<String, String> {
+    let name = format!("day_{:02}", number);
+    let header = "pub static DAYS: [Day; ";
+    let Some(start) = source.find(header).map(|start| start + header.len()) else {
+        return Err(String::from("days.rs has no DAYS table"));
+    };
+    let Some(length) = source[start..].find(']').map(|end| start + end) else {
+        return Err(String::from("The length of the DAYS table is missing"));
+    };
+    let Ok(count) = source[start..length].parse::<usize>() else {
+        return Err(String::from("The length of the DAYS table is not a number"));
+    };
+    let Some(end) = source.find("\n];\n") else {
+        return Err(String::from("The DAYS table is not closed"));
+    };
+
+    let mut solvers = String::new();
+    for part in 1..=2 {
+        solvers.push_str(&format!(
+            "fn {name}_part_{part}(path: &Path, options: &RunOptions) -> Result<Solved, String> {{\n    timed(\n        path,\n        |path| {name}::parse(path),\n        |entries| Ok(answer!(options.integer, {name}::solve_part_{part}(entries)?)),\n    )\n}}\n\n"
+        ));
+    }
+    let mut entry = format!(
+        "    Day {{\n        number: {number},\n        directory: \"{name}\",\n        input: \"puzzle01_input\",\n        version: 1,\n        parts: [\n"
+    );
+    for part in 1..=2 {
+        entry.push_str(&format!(
+            "            Part {{\n                solve: {name}_part_{part},\n                // No byte parser yet.\n                solve_bytes: {name}_part_{part},\n            }},\n"
+        ));
+    }
+    entry.push_str(&format!(
+        "        ],\n        generate: {name}::gen::generate,\n    }},"
+    ));
+
+    let table = start - header.len();
+    Ok(format!(
+        "{}{}{}{}{}\n{}\n{}",
+        &source[..table],
+        solvers,
+        header,
+        count + 1,
+        &source[length..end],
+        entry,
+        &source[end + 1..]
+    ))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    info!("Writing {}", path.display());
+    match fs::write(path, content) {
+        Ok(()) => Ok(()),
+        Err(e) => Err(format!("Failed writing {}: {}", path.display(), e)),
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(content),
+        Err(e) => Err(format!("Failed reading {}: {}", path.display(), e)),
+    }
+}
+
+/// Creates the crate of a new day with a parser, both parts, a generator and
+/// an examples.toml that its tests and the runner check, and registers it
+/// with the workspace and the runner. `examples` are the answers of the
+/// example, as far as they are known.
+pub fn new_day(number: u8, examples: [Option<u64>; 2]) -> Result<(), String> {
+    let name = format!("day_{:02}", number);
+    let root = workspace_root();
+    let directory = root.join(&name);
+    if days::find(number).is_some() {
+        return Err(format!("Day {} is registered already", number));
+    }
+    if directory.exists() {
+        return Err(format!("{} exists already", directory.display()));
+    }
+
+    // Everything is edited in memory first, so that a failing edit leaves
+    // the workspace untouched.
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("aoc").join("Cargo.toml");
+    let days_source = root.join("aoc").join("src").join("days.rs");
+    let edits = [
+        (
+            &workspace_manifest,
+            add_member(&read(&workspace_manifest)?, &name)?,
+        ),
+        (
+            &runner_manifest,
+            add_dependency(&read(&runner_manifest)?, &name)?,
+        ),
+        (&days_source, register_day(&read(&days_source)?, number)?),
+    ];
+
+    let source = directory.join("src");
+    if let Err(e) = fs::create_dir_all(&source) {
+        return Err(format!("Failed creating {}: {}", source.display(), e));
+    }
+    write(
+        &directory.join("Cargo.toml"),
+        &CARGO_TOML.replace("{{name}}", &name),
+    )?;
+    write(&source.join("main.rs"), &MAIN_RS.replace("{{name}}", &name))?;
+    write(&source.join("lib.rs"), &LIB_RS.replace("{{name}}", &name))?;
+    write(&source.join("gen.rs"), GEN_RS)?;
+    write(&directory.join("puzzle01_input"), "")?;
+    write(&directory.join("puzzle01_input_test"), "")?;
+    write(
+        &directory.join("examples.toml"),
+        &examples_manifest(examples),
+    )?;
+
+    for (path, content) in edits {
+        write(path, &content)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add_dependency, add_member, examples_manifest, register_day};
+    use aoc_examples::{Answer, Examples};
+
+    #[test]
+    fn manifests() {
+        assert_eq!(
+            add_member("[workspace]\nmembers = [\"aoc\", \"day_01\"]\n", "day_02").unwrap(),
+            "[workspace]\nmembers = [\"aoc\", \"day_01\", \"day_02\"]\n"
+        );
+        assert_eq!(
+            add_dependency(
+                "[dependencies]\nclap = \"4.5\"\nday_01 = { path = \"../day_01\" }\nlog = \"0.4\"\n",
+                "day_02"
+            )
+            .unwrap(),
+            "[dependencies]\nclap = \"4.5\"\nday_01 = { path = \"../day_01\" }\nday_02 = { path = \"../day_02\" }\nlog = \"0.4\"\n"
+        );
+    }
+
+    #[test]
+    fn examples() {
+        let examples = Examples::parse(&examples_manifest([Some(142), None]), "day_06").unwrap();
+        assert_eq!(examples.part(1)[0].answer, Some(Answer::Number(142)));
+        assert_eq!(examples.part(2)[0].answer, None);
+        assert_eq!(examples.part(2)[0].input, examples.part(1)[0].input);
+    }
+
+    #[test]
+    fn days_table() {
+        let source = include_str!("days.rs");
+        let count = source.matches("    Day {\n").count();
+        let registered = register_day(source, 25).unwrap();
+        assert!(registered.contains(&format!("pub static DAYS: [Day; {}]", count + 1)));
+        assert_eq!(registered.matches("    Day {\n").count(), count + 1);
+        assert!(registered.contains("fn day_25_part_2(path: &Path, options: &RunOptions)"));
+        assert!(registered.contains("generate: day_25::gen::generate,\n    },\n];\n"));
+    }
+}
@@ -0,0 +1,107 @@
+use log::debug;
+use serde::Serialize;
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+pub struct Solved {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+pub fn timed<I, A, P, S>(path: &Path, parse: P, solve: S) -> Result<Solved, String>
+where
+    A: ToString,
+    P: Fn(&Path) -> Result<I, String>,
+    S: Fn(&I) -> Result<A, String>,
+{
+    let start = Instant::now();
+    let input = parse(path)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solve(&input)?.to_string();
+    let solve_time = start.elapsed();
+    debug!(
+        "{}: parsed in {}, solved in {}",
+        path.display(),
+        format_duration(parse_time),
+        format_duration(solve_time)
+    );
+
+    Ok(Solved {
+        answer,
+        parse: parse_time,
+        solve: solve_time,
+    })
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+/// Summary of repeated measurements, all values in seconds.
+#[derive(Debug, Serialize)]
+pub struct Stats {
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Stats {
+    pub fn from_durations(durations: &[Duration]) -> Option<Stats> {
+        if durations.is_empty() {
+            return None;
+        }
+        let mut seconds: Vec<f64> = durations.iter().map(Duration::as_secs_f64).collect();
+        seconds.sort_by(f64::total_cmp);
+
+        let n = seconds.len();
+        let mean = seconds.iter().sum::<f64>() / n as f64;
+        let median = if n.is_multiple_of(2) {
+            (seconds[n / 2 - 1] + seconds[n / 2]) / 2.0
+        } else {
+            seconds[n / 2]
+        };
+        let variance = seconds.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Some(Stats {
+            mean,
+            median,
+            stddev: variance.sqrt(),
+            min: seconds[0],
+            max: seconds[n - 1],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use std::time::Duration;
+
+    #[test]
+    fn stats() {
+        let durations: Vec<Duration> = [4, 1, 3, 2].into_iter().map(Duration::from_secs).collect();
+        let stats = Stats::from_durations(&durations).unwrap();
+        assert_eq!(stats.mean, 2.5);
+        assert_eq!(stats.median, 2.5);
+        assert_eq!(stats.stddev, 1.25f64.sqrt());
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.max, 4.0);
+        assert!(Stats::from_durations(&[]).is_none());
+    }
+}
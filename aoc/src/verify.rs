@@ -0,0 +1,253 @@
+use crate::{
+    answers::{Answer, Answers},
+    days::{self, Day, RunOptions},
+    timing::Solved,
+};
+use log::{info, warn};
+use std::{fmt, path::PathBuf, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Example,
+    Input,
+}
+
+impl fmt::Display for InputKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputKind::Example => write!(f, "example"),
+            InputKind::Input => write!(f, "input"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Error(String),
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Error(e) => write!(f, "ERROR: {}", e),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+pub struct Check {
+    pub day: u8,
+    pub part: usize,
+    pub kind: InputKind,
+    pub path: PathBuf,
+    pub expected: Option<Answer>,
+    pub actual: Option<String>,
+    pub parse: Option<Duration>,
+    pub solve: Option<Duration>,
+    pub status: Status,
+}
+
+pub fn check(
+    day: &Day,
+    part: usize,
+    kind: InputKind,
+    path: PathBuf,
+    expected: Option<Answer>,
+    options: &RunOptions,
+) -> Check {
+    let (solved, status) = if !path.exists() {
+        (None, Status::Missing)
+    } else {
+        match (day.parts[part - 1].run(&path, options), &expected) {
+            (Ok(solved), Some(expected)) => {
+                let status = if solved.answer == expected.to_string() {
+                    Status::Pass
+                } else {
+                    Status::Fail
+                };
+                (Some(solved), status)
+            }
+            (Ok(solved), None) => (Some(solved), Status::Missing),
+            (Err(e), _) => (None, Status::Error(e)),
+        }
+    };
+
+    match &status {
+        Status::Fail | Status::Error(_) => warn!(
+            "Day {} part {} on {}: {}",
+            day.number,
+            part,
+            path.display(),
+            status
+        ),
+        _ => info!(
+            "Day {} part {} on {}: {}",
+            day.number,
+            part,
+            path.display(),
+            status
+        ),
+    }
+
+    let (actual, parse, solve) = match solved {
+        Some(Solved {
+            answer,
+            parse,
+            solve,
+        }) => (Some(answer), Some(parse), Some(solve)),
+        None => (None, None, None),
+    };
+    Check {
+        day: day.number,
+        part,
+        kind,
+        path,
+        expected,
+        actual,
+        parse,
+        solve,
+        status,
+    }
+}
+
+pub fn verify<'a, I>(
+    days: I,
+    kinds: &[InputKind],
+    answers: &Answers,
+    options: &RunOptions,
+) -> Vec<Check>
+where
+    I: IntoIterator<Item = &'a Day>,
+{
+    let mut checks = Vec::new();
+    for day in days {
+        // A day without a readable manifest still shows up, as one error
+        // per part.
+        let examples = day.examples();
+        for part in 1..=day.parts.len() {
+            for kind in kinds {
+                match kind {
+                    InputKind::Example => match &examples {
+                        Ok(examples) if examples.part(part).is_empty() => {
+                            checks.push(missing_examples(day, part))
+                        }
+                        Ok(examples) => {
+                            for example in examples.part(part) {
+                                checks.push(check(
+                                    day,
+                                    part,
+                                    *kind,
+                                    example.input.clone(),
+                                    example.answer.clone(),
+                                    options,
+                                ));
+                            }
+                        }
+                        Err(e) => checks.push(Check {
+                            status: Status::Error(e.clone()),
+                            ..missing_examples(day, part)
+                        }),
+                    },
+                    InputKind::Input => {
+                        let expected = answers
+                            .get(day.directory, part)
+                            .and_then(|part_answers| part_answers.input.clone());
+                        checks.push(check(day, part, *kind, day.input_path(), expected, options));
+                    }
+                }
+            }
+        }
+    }
+    checks
+}
+
+// The check of a part that lists no examples, pointing at the manifest.
+fn missing_examples(day: &Day, part: usize) -> Check {
+    Check {
+        day: day.number,
+        part,
+        kind: InputKind::Example,
+        path: days::workspace_root()
+            .join(day.directory)
+            .join("examples.toml"),
+        expected: None,
+        actual: None,
+        parse: None,
+        solve: None,
+        status: Status::Missing,
+    }
+}
+
+pub fn print_table(checks: &[Check]) {
+    println!(
+        "{:>3}  {:>4}  {:<19}  {:>16}  {:>16}  Status",
+        "Day", "Part", "Input", "Expected", "Actual"
+    );
+    for check in checks {
+        // A part may have several examples, which are told apart by file.
+        let input = match (check.kind, check.path.file_name()) {
+            (InputKind::Example, Some(name)) => name.to_string_lossy().into_owned(),
+            _ => check.kind.to_string(),
+        };
+        println!(
+            "{:>3}  {:>4}  {:<19}  {:>16}  {:>16}  {}",
+            check.day,
+            check.part,
+            input,
+            check
+                .expected
+                .as_ref()
+                .map(|answer| answer.to_string())
+                .unwrap_or_else(|| String::from("-")),
+            check.actual.as_deref().unwrap_or("-"),
+            check.status
+        );
+    }
+    let count = |status: fn(&Status) -> bool| checks.iter().filter(|c| status(&c.status)).count();
+    println!(
+        "{} passed, {} failed, {} missing",
+        count(|s| *s == Status::Pass),
+        count(|s| matches!(s, Status::Fail | Status::Error(_))),
+        count(|s| *s == Status::Missing)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify, InputKind, Status};
+    use crate::{
+        answers::Answers,
+        days::{self, RunOptions},
+    };
+
+    fn assert_all_pass(kind: InputKind) {
+        let answers = Answers::load(days::workspace_root().join("answers.toml")).unwrap();
+        for check in verify(days::DAYS.iter(), &[kind], &answers, &RunOptions::default()) {
+            assert_eq!(
+                check.status,
+                Status::Pass,
+                "day {} part {} {}: expected {:?}, got {:?}",
+                check.day,
+                check.part,
+                check.kind,
+                check.expected,
+                check.actual
+            );
+        }
+    }
+
+    #[test]
+    fn examples_match_answers() {
+        assert_all_pass(InputKind::Example);
+    }
+
+    #[test]
+    fn inputs_match_answers() {
+        assert_all_pass(InputKind::Input);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{
+    days::{workspace_root, Day},
+    timing::format_duration,
+};
+use log::{debug, warn};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    env,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::mpsc,
+    time::Duration,
+};
+
+// Editors save in several steps, so events this close together are one change.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// One part as `run --format json` reports it.
+#[derive(Debug, Deserialize)]
+pub struct Outcome {
+    pub part: usize,
+    pub input: String,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub parse: Option<f64>,
+    pub solve: Option<f64>,
+    pub status: String,
+    pub error: Option<String>,
+}
+
+// Swap files, backups and build output change without the day changing.
+fn ignored(path: &Path) -> bool {
+    let hidden = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_none_or(|name| name.starts_with('.') || name.ends_with('~'));
+    hidden
+        || path.components().any(|component| {
+            component.as_os_str() == "target" || component.as_os_str() == "proptest-regressions"
+        })
+}
+
+fn relevant(event: &Event) -> bool {
+    matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) && event.paths.iter().any(|path| !ignored(path))
+}
+
+// Runs the day through a freshly built runner, so that changed sources are
+// compiled first. Build errors go straight to the terminal.
+fn run(day: &Day, examples: bool, options: &[String]) -> Result<Vec<Outcome>, String> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command
+        .current_dir(workspace_root())
+        .args(["run", "--quiet", "-p", "aoc"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command
+        .args(["--", "run", "--format", "json", "--day"])
+        .arg(day.number.to_string())
+        .args(options)
+        // The sources change between runs without the solver version.
+        .arg("--no-cache");
+    if examples {
+        command.arg("--examples");
+    }
+    debug!("Running {:?}", command);
+    let output = match command.stderr(Stdio::inherit()).output() {
+        Ok(output) => output,
+        Err(e) => return Err(format!("Failed running cargo: {}", e)),
+    };
+    if output.stdout.is_empty() {
+        return Err(format!("The run failed ({})", output.status));
+    }
+    match serde_json::from_slice(&output.stdout) {
+        Ok(outcomes) => Ok(outcomes),
+        Err(e) => Err(format!("Failed reading the results of the run: {}", e)),
+    }
+}
+
+fn seconds(seconds: Option<f64>) -> String {
+    match seconds {
+        Some(seconds) => format_duration(Duration::from_secs_f64(seconds)),
+        None => String::from("-"),
+    }
+}
+
+/// A part of the real input, with its answer and times against the previous
+/// run if there was one.
+pub fn describe(outcome: &Outcome, previous: Option<&Outcome>) -> String {
+    let answer = match (&outcome.answer, &outcome.error) {
+        (Some(answer), _) => answer.clone(),
+        (None, Some(e)) => format!("ERROR: {}", e),
+        (None, None) => String::from("-"),
+    };
+    let Some(previous) = previous else {
+        return format!(
+            "Part {}: {}  parse {}  solve {}",
+            outcome.part,
+            answer,
+            seconds(outcome.parse),
+            seconds(outcome.solve)
+        );
+    };
+    let change = if previous.answer == outcome.answer {
+        String::from("unchanged")
+    } else {
+        format!("was {}", previous.answer.as_deref().unwrap_or("-"))
+    };
+    format!(
+        "Part {}: {} ({})  parse {} (was {})  solve {} (was {})",
+        outcome.part,
+        answer,
+        change,
+        seconds(outcome.parse),
+        seconds(previous.parse),
+        seconds(outcome.solve),
+        seconds(previous.solve)
+    )
+}
+
+// Checks the examples, and only if they pass solves the real input.
+fn cycle(day: &Day, options: &[String], previous: &mut BTreeMap<usize, Outcome>) {
+    println!("--- Day {} ---", day.number);
+    let examples = match run(day, true, options) {
+        Ok(examples) => examples,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+    let failed: Vec<&Outcome> = examples
+        .iter()
+        .filter(|example| example.status == "fail" || example.status == "error")
+        .collect();
+    if !failed.is_empty() {
+        for example in failed {
+            match &example.error {
+                Some(e) => println!("Part {} on {}: ERROR: {}", example.part, example.input, e),
+                None => println!(
+                    "Part {} on {}: expected {}, got {}",
+                    example.part,
+                    example.input,
+                    example.expected.as_deref().unwrap_or("-"),
+                    example.answer.as_deref().unwrap_or("-")
+                ),
+            }
+        }
+        println!("Examples fail, skipping the input");
+        return;
+    }
+    println!("Examples pass ({})", examples.len());
+
+    match run(day, false, options) {
+        Ok(outcomes) => {
+            for outcome in outcomes {
+                println!("{}", describe(&outcome, previous.get(&outcome.part)));
+                previous.insert(outcome.part, outcome);
+            }
+        }
+        Err(e) => println!("{}", e),
+    }
+}
+
+// The day with its sources, inputs and examples.toml, and the crates its
+// parsers and example checks are built from.
+fn watched(root: &Path, day: &Day) -> [PathBuf; 3] {
+    [
+        root.join(day.directory),
+        root.join("aoc_parse").join("src"),
+        root.join("aoc_examples").join("src"),
+    ]
+}
+
+/// Runs the examples and then the input of a day every time its sources,
+/// inputs or examples change, until interrupted. `options` are passed on to
+/// every run.
+pub fn watch(day: &Day, options: &[String]) -> Result<(), String> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(sender) {
+        Ok(watcher) => watcher,
+        Err(e) => return Err(format!("Failed watching files: {}", e)),
+    };
+    let root = workspace_root();
+    let directories = watched(&root, day);
+    for directory in &directories {
+        if let Err(e) = watcher.watch(directory, RecursiveMode::Recursive) {
+            return Err(format!("Failed watching {}: {}", directory.display(), e));
+        }
+    }
+    let names: Vec<String> = directories
+        .iter()
+        .map(|directory| directory.display().to_string())
+        .collect();
+    println!("Watching {}, press Ctrl-C to stop", names.join(", "));
+
+    let mut previous = BTreeMap::new();
+    cycle(day, options, &mut previous);
+    loop {
+        match receiver.recv() {
+            Ok(Ok(event)) if relevant(&event) => debug!("Changed: {:?}", event.paths),
+            Ok(Ok(_)) => continue,
+            Ok(Err(e)) => {
+                warn!("Watching files: {}", e);
+                continue;
+            }
+            Err(_) => return Err(String::from("Stopped receiving file changes")),
+        }
+        while receiver.recv_timeout(SETTLE).is_ok() {}
+        cycle(day, options, &mut previous);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{describe, ignored, watched, Outcome};
+    use crate::days::{workspace_root, DAYS};
+    use std::path::Path;
+
+    fn outcome(answer: &str, solve: f64) -> Outcome {
+        Outcome {
+            part: 1,
+            input: String::from("day_01/puzzle01_input"),
+            answer: Some(String::from(answer)),
+            expected: None,
+            parse: Some(0.001),
+            solve: Some(solve),
+            status: String::from("ok"),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn answers_against_the_previous_run() {
+        assert_eq!(
+            describe(&outcome("142", 0.002), None),
+            "Part 1: 142  parse 1.0 ms  solve 2.0 ms"
+        );
+        assert_eq!(
+            describe(&outcome("142", 0.002), Some(&outcome("142", 0.003))),
+            "Part 1: 142 (unchanged)  parse 1.0 ms (was 1.0 ms)  solve 2.0 ms (was 3.0 ms)"
+        );
+        assert!(
+            describe(&outcome("281", 0.002), Some(&outcome("142", 0.002)))
+                .starts_with("Part 1: 281 (was 142)")
+        );
+    }
+
+    #[test]
+    fn shared_crates_are_watched() {
+        let root = workspace_root();
+        let directories = watched(&root, &DAYS[4]);
+        for file in [
+            "day_05/src/lib.rs",
+            "day_05/examples.toml",
+            "day_05/puzzle01_input",
+            "aoc_parse/src/lib.rs",
+            "aoc_examples/src/lib.rs",
+        ] {
+            let path = root.join(file);
+            assert!(path.exists(), "{}", file);
+            assert!(
+                directories
+                    .iter()
+                    .any(|directory| path.starts_with(directory)),
+                "{}",
+                file
+            );
+        }
+    }
+
+    #[test]
+    fn editor_files_are_ignored() {
+        assert!(ignored(Path::new("day_01/src/.lib.rs.swp")));
+        assert!(ignored(Path::new("day_01/src/lib.rs~")));
+        assert!(ignored(Path::new("day_01/proptest-regressions/lib.txt")));
+        assert!(!ignored(Path::new("day_01/src/lib.rs")));
+        assert!(!ignored(Path::new("day_01/examples.toml")));
+    }
+}
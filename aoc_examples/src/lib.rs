@@ -0,0 +1,212 @@
+//! The examples of a day and their answers, listed per part in the
+//! `examples.toml` of the day:
+//!
+//! ```toml
+//! [[part1]]
+//! input = "puzzle01_input_test"
+//! answer = 142
+//! ```
+//!
+//! Inputs are relative to the directory of the day. A part may list any
+//! number of examples, and an example without an answer is only run. The
+//! runner checks them with `--examples`, and [`example_tests`] turns them into
+//! the tests of a day.
+//!
+//! The benchmarks of the days take their inputs from [`bench_inputs`], and
+//! [`failing_reader`] tests how the readers of the days handle I/O errors.
+
+use serde::Deserialize;
+use std::{
+    env, fmt, fs,
+    io::{self, BufRead, Read},
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Example {
+    pub input: PathBuf,
+    pub answer: Option<Answer>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Manifest {
+    #[serde(default)]
+    part1: Vec<Example>,
+    #[serde(default)]
+    part2: Vec<Example>,
+}
+
+#[derive(Debug, Default)]
+pub struct Examples {
+    parts: [Vec<Example>; 2],
+}
+
+impl Examples {
+    /// Reads the `examples.toml` in the directory of a day.
+    pub fn load<P>(directory: P) -> Result<Examples, String>
+    where
+        P: AsRef<Path>,
+    {
+        let path = directory.as_ref().join("examples.toml");
+        match fs::read_to_string(&path) {
+            Ok(content) => Examples::parse(&content, directory),
+            Err(e) => Err(format!("Failed reading {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Parses the content of an `examples.toml`, with the inputs relative to
+    /// `directory`.
+    pub fn parse<P>(content: &str, directory: P) -> Result<Examples, String>
+    where
+        P: AsRef<Path>,
+    {
+        let manifest: Manifest = match toml::from_str(content) {
+            Ok(manifest) => manifest,
+            Err(e) => return Err(format!("Failed parsing examples: {}", e)),
+        };
+        let mut parts = [manifest.part1, manifest.part2];
+        for example in parts.iter_mut().flatten() {
+            example.input = directory.as_ref().join(&example.input);
+        }
+        Ok(Examples { parts })
+    }
+
+    pub fn part(&self, part: usize) -> &[Example] {
+        match part {
+            1 | 2 => &self.parts[part - 1],
+            _ => &[],
+        }
+    }
+}
+
+/// Runs `solve` on every example of `part` of the day in `directory` and
+/// panics with all examples whose answer differs.
+pub fn check<F, A>(directory: &str, part: usize, solve: F)
+where
+    F: Fn(&Path) -> A,
+    A: ToString,
+{
+    let examples = Examples::load(directory).unwrap();
+    let examples = examples.part(part);
+    assert!(!examples.is_empty(), "Part {} has no examples", part);
+    let mut failures = Vec::new();
+    for example in examples {
+        let actual = solve(&example.input).to_string();
+        if let Some(expected) = &example.answer {
+            if actual != expected.to_string() {
+                failures.push(format!(
+                    "{}: expected {}, got {}",
+                    example.input.display(),
+                    expected,
+                    actual
+                ));
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "Part {} fails on examples:\n{}",
+        part,
+        failures.join("\n")
+    );
+}
+
+/// Tests both parts of a day against its `examples.toml`. Each solver takes
+/// the path of an example and returns the answer.
+#[macro_export]
+macro_rules! example_tests {
+    ($part1:expr, $part2:expr $(,)?) => {
+        #[test]
+        fn examples_part_1() {
+            $crate::check(env!("CARGO_MANIFEST_DIR"), 1, $part1);
+        }
+
+        #[test]
+        fn examples_part_2() {
+            $crate::check(env!("CARGO_MANIFEST_DIR"), 2, $part2);
+        }
+    };
+}
+
+/// The real input of a day and inputs of every size in `sizes` from its
+/// generator, named `input` and `gen_<size>` for benchmark ids. Generated
+/// inputs are written to temporary files named after `day`.
+pub fn bench_inputs(
+    day: &str,
+    sizes: &[usize],
+    generate: fn(usize, u64) -> String,
+) -> Vec<(String, PathBuf)> {
+    let mut inputs = vec![(String::from("input"), PathBuf::from("puzzle01_input"))];
+    for size in sizes {
+        let path = env::temp_dir().join(format!("{}_bench_gen_{}", day, size));
+        fs::write(&path, generate(*size, 0)).unwrap();
+        inputs.push((format!("gen_{}", size), path));
+    }
+    inputs
+}
+
+/// A reader that yields `content` and then fails, like a disk or a pipe
+/// failing in the middle of an input.
+pub fn failing_reader(content: &'static str) -> impl BufRead {
+    io::BufReader::new(content.as_bytes().chain(Failing))
+}
+
+struct Failing;
+
+impl Read for Failing {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Err(io::Error::other("reading failed"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Answer, Examples};
+    use std::path::Path;
+
+    #[test]
+    fn parts_list_any_number_of_examples() {
+        let examples = Examples::parse(
+            r#"
+            [[part1]]
+            input = "a"
+            answer = 142
+
+            [[part1]]
+            input = "b"
+
+            [[part2]]
+            input = "a"
+            answer = "abc"
+            "#,
+            "day_01",
+        )
+        .unwrap();
+        let part1 = examples.part(1);
+        assert_eq!(part1.len(), 2);
+        assert_eq!(part1[0].input, Path::new("day_01/a"));
+        assert_eq!(part1[0].answer, Some(Answer::Number(142)));
+        assert_eq!(part1[1].answer, None);
+        assert_eq!(
+            examples.part(2)[0].answer,
+            Some(Answer::Text(String::from("abc")))
+        );
+        assert!(examples.part(3).is_empty());
+    }
+}
@@ -0,0 +1,93 @@
+//! Helpers for parsing a whole input held as bytes, without allocating per
+//! line. They do not track columns, errors of the byte parsers only name the
+//! line.
+
+/// The lines of `input` without their line endings. A trailing newline does
+/// not start another line.
+pub fn lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let input = input.strip_suffix(b"\n").unwrap_or(input);
+    let mut lines = input.split(|&byte| byte == b'\n');
+    if input.is_empty() {
+        lines.next();
+    }
+    lines.map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+/// Lines of `input` in blocks separated by blank lines. Runs of blank lines
+/// count as one separator. Every line comes with its number, starting at 1.
+pub fn sections(input: &[u8]) -> Vec<Vec<(usize, &[u8])>> {
+    let mut sections = Vec::new();
+    let mut section = Vec::new();
+    for (i, line) in lines(input).enumerate() {
+        if skip_spaces(line).is_empty() {
+            if !section.is_empty() {
+                sections.push(section);
+                section = Vec::new();
+            }
+        } else {
+            section.push((i + 1, line));
+        }
+    }
+    if !section.is_empty() {
+        sections.push(section);
+    }
+    sections
+}
+
+/// `input` without its leading spaces and tabs.
+pub fn skip_spaces(input: &[u8]) -> &[u8] {
+    let start = input
+        .iter()
+        .position(|&byte| byte != b' ' && byte != b'\t')
+        .unwrap_or(input.len());
+    &input[start..]
+}
+
+/// The number that `input` starts with and the bytes after it. None if
+/// `input` does not start with a digit or the number does not fit into `T`.
+pub fn number<T>(input: &[u8]) -> Option<(T, &[u8])>
+where
+    T: TryFrom<u64>,
+{
+    let end = input
+        .iter()
+        .position(|byte| !byte.is_ascii_digit())
+        .unwrap_or(input.len());
+    if end == 0 {
+        return None;
+    }
+    let mut value: u64 = 0;
+    for &digit in &input[..end] {
+        value = value
+            .checked_mul(10)?
+            .checked_add(u64::from(digit - b'0'))?;
+    }
+    Some((T::try_from(value).ok()?, &input[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bytes::{lines, number, sections};
+
+    #[test]
+    fn lines_and_sections() {
+        assert_eq!(lines(b"").count(), 0);
+        assert_eq!(
+            lines(b"a\r\n\nb\n").collect::<Vec<_>>(),
+            [&b"a"[..], b"", b"b"]
+        );
+        assert_eq!(
+            sections(b"a\nb\n\n \n\nc"),
+            [vec![(1, &b"a"[..]), (2, b"b")], vec![(6, &b"c"[..])]]
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(number::<u32>(b"42 rest"), Some((42, &b" rest"[..])));
+        assert_eq!(number::<u32>(b"x42"), None);
+        assert_eq!(number::<u32>(b"4294967296"), None);
+        assert_eq!(number::<u64>(b"4294967296"), Some((4294967296, &b""[..])));
+        assert_eq!(number::<u64>(b"18446744073709551616"), None);
+    }
+}
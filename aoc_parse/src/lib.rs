@@ -0,0 +1,547 @@
+//! Small parser combinators for the puzzle inputs.
+//!
+//! A parser takes an [`Input`], the unparsed rest of a text together with its
+//! position, and returns the parsed value and the input after it. Errors carry
+//! the line and column they happened at.
+//!
+//! An error of [`Input::expected`] right where a parser started only means
+//! that it did not match, so [`many`], [`separated`], [`optional`] and [`alt`]
+//! stop there or try something else. Once a parser got past its first char,
+//! its errors end the parse, so they point at the actual mistake instead of
+//! where the backtracking ended. The same holds for errors of
+//! [`Input::error`], which are raised after the input was recognised, e.g. for
+//! a number that does not fit its type. Spaces are skipped after tokens, see
+//! [`token`].
+
+pub mod bytes;
+
+use std::{error, fmt, str::FromStr};
+
+/// Position in a text. Lines and columns start at 1, columns count chars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub span: Span,
+    pub message: String,
+    fatal: bool,
+}
+
+impl ParseError {
+    // Whether a parser starting at `input` just did not match.
+    fn is_mismatch_at(&self, input: &Input) -> bool {
+        !self.fatal && self.span == input.span
+    }
+
+    /// Prefixes the message with what was being parsed, e.g. a section name.
+    pub fn context(self, context: &str) -> ParseError {
+        ParseError {
+            message: format!("{}: {}", context, self.message),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.span, self.message)
+    }
+}
+
+impl error::Error for ParseError {}
+
+impl From<ParseError> for String {
+    fn from(e: ParseError) -> String {
+        e.to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a> {
+    rest: &'a str,
+    span: Span,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(text: &'a str) -> Input<'a> {
+        Input::at_line(text, 1)
+    }
+
+    /// Input for a text that starts at `line` of a larger one, e.g. a single
+    /// line read from a file.
+    pub fn at_line(text: &'a str, line: usize) -> Input<'a> {
+        Input {
+            rest: text,
+            span: Span { line, column: 1 },
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    /// The input after the first `bytes` bytes.
+    pub fn advance(&self, bytes: usize) -> Input<'a> {
+        let mut span = self.span;
+        for c in self.rest[..bytes].chars() {
+            if c == '\n' {
+                span.line += 1;
+                span.column = 1;
+            } else {
+                span.column += 1;
+            }
+        }
+        Input {
+            rest: &self.rest[bytes..],
+            span,
+        }
+    }
+
+    /// A parser did not match here. Repeating parsers stop at this error.
+    pub fn expected(&self, what: &str) -> ParseError {
+        let found = match self.peek() {
+            Some(c) => format!("'{}'", c.escape_debug()),
+            None => String::from("the end of the line"),
+        };
+        ParseError {
+            span: self.span,
+            message: format!("expected {}, found {}", what, found),
+            fatal: false,
+        }
+    }
+
+    /// The input here is malformed. This error ends the parse.
+    pub fn error<S>(&self, message: S) -> ParseError
+    where
+        S: Into<String>,
+    {
+        ParseError {
+            span: self.span,
+            message: message.into(),
+            fatal: true,
+        }
+    }
+}
+
+pub type ParseResult<'a, T> = Result<(T, Input<'a>), ParseError>;
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: Input<'a>) -> ParseResult<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Input<'a>) -> ParseResult<'a, T>,
+{
+    fn parse(&self, input: Input<'a>) -> ParseResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Matches `tag` exactly.
+pub fn tag<'a, 't>(tag: &'t str) -> impl Parser<'a, &'a str> + 't {
+    move |input: Input<'a>| {
+        if input.rest.starts_with(tag) {
+            Ok((&input.rest[..tag.len()], input.advance(tag.len())))
+        } else {
+            Err(input.expected(&format!("'{}'", tag)))
+        }
+    }
+}
+
+/// The index of the first of `tags` that matches.
+pub fn one_of<'a>(tags: &'static [&'static str]) -> impl Parser<'a, usize> {
+    move |input: Input<'a>| match tags.iter().position(|tag| input.rest.starts_with(tag)) {
+        Some(i) => Ok((i, input.advance(tags[i].len()))),
+        None => Err(input.expected(&format!("one of {}", tags.join(", ")))),
+    }
+}
+
+/// Any single char.
+pub fn any_char<'a>() -> impl Parser<'a, char> {
+    move |input: Input<'a>| match input.peek() {
+        Some(c) => Ok((c, input.advance(c.len_utf8()))),
+        None => Err(input.expected("a character")),
+    }
+}
+
+/// Skips any number of spaces and tabs, including none.
+pub fn spaces<'a>() -> impl Parser<'a, ()> {
+    move |input: Input<'a>| {
+        let length = input.rest.len() - input.rest.trim_start_matches([' ', '\t']).len();
+        Ok(((), input.advance(length)))
+    }
+}
+
+/// One or more ASCII digits.
+pub fn digits<'a>() -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let length = input.rest.len()
+            - input
+                .rest
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        if length == 0 {
+            Err(input.expected("a digit"))
+        } else {
+            Ok((&input.rest[..length], input.advance(length)))
+        }
+    }
+}
+
+/// One or more ASCII letters.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let length = input.rest.len()
+            - input
+                .rest
+                .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+                .len();
+        if length == 0 {
+            Err(input.expected("a word"))
+        } else {
+            Ok((&input.rest[..length], input.advance(length)))
+        }
+    }
+}
+
+/// An unsigned number that has to fit into `T`.
+pub fn number<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr,
+{
+    move |input: Input<'a>| {
+        let (digits, rest) = digits().parse(input)?;
+        match digits.parse() {
+            Ok(number) => Ok((number, rest)),
+            Err(_) => Err(input.error(format!(
+                "{} does not fit into a {}",
+                digits,
+                std::any::type_name::<T>()
+            ))),
+        }
+    }
+}
+
+pub fn map<'a, A, B, P, F>(parser: P, f: F) -> impl Parser<'a, B>
+where
+    P: Parser<'a, A>,
+    F: Fn(A) -> B,
+{
+    move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((f(value), rest))
+    }
+}
+
+pub fn pair<'a, A, B, P, Q>(first: P, second: Q) -> impl Parser<'a, (A, B)>
+where
+    P: Parser<'a, A>,
+    Q: Parser<'a, B>,
+{
+    move |input: Input<'a>| {
+        let (a, input) = first.parse(input)?;
+        let (b, input) = second.parse(input)?;
+        Ok(((a, b), input))
+    }
+}
+
+/// Runs both parsers and keeps the value of the second.
+pub fn preceded<'a, A, B, P, Q>(first: P, second: Q) -> impl Parser<'a, B>
+where
+    P: Parser<'a, A>,
+    Q: Parser<'a, B>,
+{
+    map(pair(first, second), |(_, b)| b)
+}
+
+/// Runs both parsers and keeps the value of the first.
+pub fn terminated<'a, A, B, P, Q>(first: P, second: Q) -> impl Parser<'a, A>
+where
+    P: Parser<'a, A>,
+    Q: Parser<'a, B>,
+{
+    map(pair(first, second), |(a, _)| a)
+}
+
+/// `parser` and the spaces after it.
+pub fn token<'a, T, P>(parser: P) -> impl Parser<'a, T>
+where
+    P: Parser<'a, T>,
+{
+    terminated(parser, spaces())
+}
+
+/// `parser` after the label `label` and optional spaces, e.g.
+/// `labelled("Game", number())` for "Game 12".
+pub fn labelled<'a, T, P>(label: &'static str, parser: P) -> impl Parser<'a, T>
+where
+    P: Parser<'a, T>,
+{
+    preceded(token(tag(label)), parser)
+}
+
+/// `first` if it matches, `second` otherwise.
+pub fn alt<'a, T, P, Q>(first: P, second: Q) -> impl Parser<'a, T>
+where
+    P: Parser<'a, T>,
+    Q: Parser<'a, T>,
+{
+    move |input: Input<'a>| match first.parse(input) {
+        Err(e) if e.is_mismatch_at(&input) => second.parse(input),
+        result => result,
+    }
+}
+
+/// `parser` if it matches, None otherwise.
+pub fn optional<'a, T, P>(parser: P) -> impl Parser<'a, Option<T>>
+where
+    P: Parser<'a, T>,
+{
+    move |input: Input<'a>| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(e) if e.is_mismatch_at(&input) => Ok((None, input)),
+        Err(e) => Err(e),
+    }
+}
+
+/// `parser` repeated as often as it matches, including never.
+pub fn many<'a, T, P>(parser: P) -> impl Parser<'a, Vec<T>>
+where
+    P: Parser<'a, T>,
+{
+    move |mut input: Input<'a>| {
+        let mut values = Vec::new();
+        loop {
+            match parser.parse(input) {
+                // A parser that matches nothing would match forever.
+                Ok((_, rest)) if rest == input => return Ok((values, input)),
+                Ok((value, rest)) => {
+                    values.push(value);
+                    input = rest;
+                }
+                Err(e) if e.is_mismatch_at(&input) => return Ok((values, input)),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// One or more `item`s with a `separator` between each two.
+pub fn separated<'a, T, S, P, Q>(item: P, separator: Q) -> impl Parser<'a, Vec<T>>
+where
+    P: Parser<'a, T>,
+    Q: Parser<'a, S>,
+{
+    move |input: Input<'a>| {
+        let (first, mut input) = item.parse(input)?;
+        let mut values = vec![first];
+        loop {
+            let rest = match separator.parse(input) {
+                Ok((_, rest)) => rest,
+                Err(e) if e.is_mismatch_at(&input) => return Ok((values, input)),
+                Err(e) => return Err(e),
+            };
+            let (value, rest) = item.parse(rest)?;
+            values.push(value);
+            input = rest;
+        }
+    }
+}
+
+/// A parsed value with the positions of its first char and of the char after
+/// it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub start: Span,
+    pub end: Span,
+}
+
+pub fn spanned<'a, T, P>(parser: P) -> impl Parser<'a, Spanned<T>>
+where
+    P: Parser<'a, T>,
+{
+    move |input: Input<'a>| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((
+            Spanned {
+                value,
+                start: input.span(),
+                end: rest.span(),
+            },
+            rest,
+        ))
+    }
+}
+
+/// Runs `parser` on the whole input. Only trailing spaces may be left over.
+pub fn parse_all<'a, T, P>(parser: P, input: Input<'a>) -> Result<T, ParseError>
+where
+    P: Parser<'a, T>,
+{
+    let (value, rest) = terminated(parser, spaces()).parse(input)?;
+    let rest = match rest.peek() {
+        Some('\r') => rest.advance(1),
+        _ => rest,
+    };
+    if rest.is_empty() {
+        Ok(value)
+    } else {
+        Err(rest.expected("the end of the line"))
+    }
+}
+
+/// Every line of `text` as an input of its own, numbered from 1.
+pub fn lines(text: &str) -> impl Iterator<Item = Input<'_>> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| Input::at_line(line, i + 1))
+}
+
+/// Splits `text` into blocks of lines separated by blank lines. Runs of blank
+/// lines count as one separator.
+pub fn sections(text: &str) -> Vec<Vec<Input<'_>>> {
+    let mut sections = Vec::new();
+    let mut section = Vec::new();
+    for line in lines(text) {
+        if line.rest().trim().is_empty() {
+            if !section.is_empty() {
+                sections.push(section);
+                section = Vec::new();
+            }
+        } else {
+            section.push(line);
+        }
+    }
+    if !section.is_empty() {
+        sections.push(section);
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        alt, digits, labelled, many, map, number, one_of, optional, pair, parse_all, preceded,
+        sections, separated, spanned, tag, token, word, Input, Parser, Span,
+    };
+
+    #[test]
+    fn labelled_lists() {
+        let card = || {
+            pair(
+                labelled("Card", number::<u32>()),
+                preceded(token(tag(":")), many(token(number::<u32>()))),
+            )
+        };
+        assert_eq!(
+            parse_all(card(), Input::new("Card  3: 1 21  53 ")),
+            Ok((3, vec![1, 21, 53]))
+        );
+
+        let e = parse_all(card(), Input::at_line("Card 3: 1 x", 7)).unwrap_err();
+        assert_eq!(
+            e.span,
+            Span {
+                line: 7,
+                column: 11
+            }
+        );
+        assert_eq!(
+            e.to_string(),
+            "line 7, column 11: expected the end of the line, found 'x'"
+        );
+    }
+
+    #[test]
+    fn separated_lists() {
+        let draws = || {
+            let draw = separated(pair(token(number::<u32>()), token(word())), token(tag(",")));
+            separated(draw, token(tag(";")))
+        };
+        assert_eq!(
+            parse_all(draws(), Input::new("3 blue, 4 red; 2 green")),
+            Ok(vec![vec![(3, "blue"), (4, "red")], vec![(2, "green")]])
+        );
+        assert!(parse_all(draws(), Input::new("3 blue,")).is_err());
+        let e = parse_all(draws(), Input::new("3 blue, 4 7")).unwrap_err();
+        assert_eq!(e.span.column, 11);
+    }
+
+    #[test]
+    fn alternatives() {
+        let number_or_dash = || {
+            alt(
+                map(number::<u32>(), Some),
+                map(one_of(&["x", "-"]), |_| None),
+            )
+        };
+        assert_eq!(
+            parse_all(many(token(number_or_dash())), Input::new("1 x 2 - 3")),
+            Ok(vec![Some(1), None, Some(2), None, Some(3)])
+        );
+        let e = parse_all(number_or_dash(), Input::new("y")).unwrap_err();
+        assert_eq!(e.message, "expected one of x, -, found 'y'");
+    }
+
+    #[test]
+    fn numbers_out_of_range_end_the_parse() {
+        let e = parse_all(many(token(number::<u8>())), Input::new("1 2 300 4")).unwrap_err();
+        assert_eq!(e.span, Span { line: 1, column: 5 });
+        assert_eq!(e.message, "300 does not fit into a u8");
+        assert_eq!(
+            optional(number::<u8>()).parse(Input::new("x")).unwrap().0,
+            None
+        );
+        assert!(optional(number::<u8>()).parse(Input::new("256")).is_err());
+    }
+
+    #[test]
+    fn spans() {
+        let (value, rest) = preceded(tag(".."), spanned(digits()))
+            .parse(Input::at_line("..467*", 3))
+            .unwrap();
+        assert_eq!(value.value, "467");
+        assert_eq!(value.start, Span { line: 3, column: 3 });
+        assert_eq!(value.end, Span { line: 3, column: 6 });
+        assert_eq!(rest.rest(), "*");
+        assert_eq!(
+            Input::new("a\nb").advance(2).span(),
+            Span { line: 2, column: 1 }
+        );
+    }
+
+    #[test]
+    fn blank_lines_separate_sections() {
+        let sections = sections("seeds: 1\n\n\na:\n1 2 3\n\nb:\n");
+        let first_lines: Vec<(usize, &str)> = sections
+            .iter()
+            .map(|section| (section[0].span().line, section[0].rest()))
+            .collect();
+        assert_eq!(first_lines, vec![(1, "seeds: 1"), (4, "a:"), (7, "b:")]);
+        assert_eq!(sections[1].len(), 2);
+    }
+}
@@ -0,0 +1,63 @@
+use aoc_examples::bench_inputs;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_01::{bytes, first_and_last_digit, gen, read_lines, sum_digits, sum_digits_and_words};
+use std::{fs, hint::black_box};
+
+// Generated inputs of these sizes, see `gen::generate`.
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+fn bench_first_and_last_digit(c: &mut Criterion) {
+    let lines = read_lines("puzzle01_input").unwrap();
+    let mut group = c.benchmark_group("day_01/first_and_last_digit");
+    group.throughput(Throughput::Elements(lines.len() as u64));
+    group.bench_function("puzzle01_input", |b| {
+        b.iter(|| {
+            for line in &lines {
+                black_box(first_and_last_digit(line).unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_solvers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_01");
+    for (name, path) in bench_inputs("day_01", &SIZES, gen::generate) {
+        let lines = read_lines(&path).unwrap();
+        group.throughput(Throughput::Elements(lines.len() as u64));
+        group.bench_with_input(BenchmarkId::new("read_lines", &name), &path, |b, path| {
+            b.iter(|| read_lines(path).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("sum_digits", &name), &lines, |b, lines| {
+            b.iter(|| sum_digits::<u64>(lines))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("sum_digits_and_words", &name),
+            &lines,
+            |b, lines| b.iter(|| sum_digits_and_words::<u64>(lines)),
+        );
+    }
+    group.finish();
+}
+
+// Part 2 as read line by line and as parsed from the bytes of the file.
+fn bench_readers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_01/reader");
+    for (name, path) in bench_inputs("day_01", &SIZES, gen::generate) {
+        group.bench_with_input(BenchmarkId::new("lines", &name), &path, |b, path| {
+            b.iter(|| sum_digits_and_words::<u64>(&read_lines(path).unwrap()))
+        });
+        group.bench_with_input(BenchmarkId::new("bytes", &name), &path, |b, path| {
+            b.iter(|| bytes::sum_digits_and_words::<u64>(&fs::read(path).unwrap()))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_first_and_last_digit,
+    bench_solvers,
+    bench_readers
+);
+criterion_main!(benches);
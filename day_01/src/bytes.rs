@@ -0,0 +1,73 @@
+//! Solvers over the whole input held as bytes, without a `String` per line.
+//! They give the same sums and errors as the line solvers:
+//!
+//! ```
+//! let input = b"1abc2\nxtwone3four\n";
+//! assert_eq!(day_01::bytes::sum_digits::<u32>(input), Ok(12 + 33));
+//! assert_eq!(day_01::bytes::sum_digits_and_words::<u32>(input), Ok(12 + 24));
+//! ```
+
+use crate::{sum_values, NUMBERS, NUMBER_STRINGS};
+use aoc_parse::bytes::lines;
+use num_traits::{CheckedAdd, CheckedMul, NumAssign};
+
+// The digit of a written digit at the start of `line`, also taking spelled
+// out digits into account if `words` is set.
+fn digit_at(line: &[u8], words: bool) -> Option<u32> {
+    if line[0].is_ascii_digit() {
+        return Some(u32::from(line[0] - b'0'));
+    }
+    if !words {
+        return None;
+    }
+    NUMBER_STRINGS
+        .iter()
+        .position(|number_string| line.starts_with(number_string.as_bytes()))
+        .map(|i| u32::from(NUMBERS[i].as_bytes()[0] - b'0'))
+}
+
+fn value(line: &[u8], words: bool) -> Result<u32, &'static str> {
+    let mut digits = (0..line.len()).filter_map(|i| digit_at(&line[i..], words));
+    match digits.next() {
+        Some(first) => Ok(first * 10 + digits.next_back().unwrap_or(first)),
+        None => Err("No digit found"),
+    }
+}
+
+/// Same as [`crate::sum_digits`], but over the whole input at once.
+pub fn sum_digits<T>(input: &[u8]) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    sum_values(lines(input), |line| value(line, false))
+}
+
+/// Same as [`crate::sum_digits_and_words`], but over the whole input at once.
+pub fn sum_digits_and_words<T>(input: &[u8]) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    sum_values(lines(input), |line| value(line, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bytes, gen, read_lines_from_reader, sum_digits, sum_digits_and_words};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn bytes_match_lines(size in 0usize..200, seed: u64) {
+            let input = gen::generate(size, seed);
+            let lines = read_lines_from_reader(input.as_bytes()).unwrap();
+            prop_assert_eq!(
+                bytes::sum_digits::<u32>(input.as_bytes()),
+                sum_digits::<u32>(&lines)
+            );
+            prop_assert_eq!(
+                bytes::sum_digits_and_words::<u32>(input.as_bytes()),
+                sum_digits_and_words::<u32>(&lines)
+            );
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Random calibration documents for tests and benchmarks.
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Generates `size` calibration lines mixing letters, digits and spelled out
+/// digits. Every line contains at least one numeric digit, so both parts can
+/// solve it.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut pieces: Vec<String> = vec![rng.gen_range(1..=9u32).to_string()];
+        for _ in 0..rng.gen_range(0..8) {
+            pieces.push(match rng.gen_range(0..3) {
+                0 => rng.gen_range(1..=9u32).to_string(),
+                1 => String::from(*WORDS.choose(&mut rng).unwrap()),
+                _ => (0..rng.gen_range(1..5))
+                    .map(|_| rng.gen_range(b'a'..=b'z') as char)
+                    .collect(),
+            });
+        }
+        pieces.shuffle(&mut rng);
+        input.push_str(&pieces.concat());
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use crate::{read_lines_from_reader, sum_digits, sum_digits_and_words};
+
+    #[test]
+    fn every_line_has_a_numeric_digit() {
+        let input = generate(500, 1);
+        assert_eq!(input, generate(500, 1));
+        let lines = read_lines_from_reader(input.as_bytes()).unwrap();
+        assert_eq!(lines.len(), 500);
+        assert!(lines
+            .iter()
+            .all(|line| line.bytes().any(|byte| byte.is_ascii_digit())));
+        assert_eq!(sum_digits::<u32>(&lines), Ok(27537));
+        assert_eq!(sum_digits_and_words::<u32>(&lines), Ok(27284));
+    }
+}
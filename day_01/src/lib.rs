@@ -0,0 +1,269 @@
+//! Day 1: Trebuchet?! The calibration value of a line is made of its first
+//! and its last digit. Part 1 only counts numeric digits, part 2 also counts
+//! digits spelled out as words.
+//!
+//! Lines are read into memory with [`read_lines_from_reader`] and summed with
+//! [`sum_digits`] or [`sum_digits_and_words`]:
+//!
+//! ```
+//! let lines = day_01::read_lines_from_reader("1abc2\ntreb7uchet\n".as_bytes()).unwrap();
+//! assert_eq!(day_01::sum_digits::<u32>(&lines), Ok(12 + 77));
+//!
+//! let lines = day_01::read_lines_from_reader("two1nine\nabc\n".as_bytes()).unwrap();
+//! assert_eq!(
+//!     day_01::sum_digits_and_words::<u32>(&lines),
+//!     Err(String::from("line 2: No digit found"))
+//! );
+//! ```
+//!
+//! A line is worth at most 99, so a `u32` sum holds over 40 million lines and
+//! wider `T`s like `num_bigint::BigUint` any number of them. A sum that
+//! outgrows `T` is an error.
+//!
+//! The `*_streaming` functions solve inputs too large for memory, the
+//! [`bytes`] module solves inputs held as bytes.
+
+#![warn(missing_docs)]
+
+pub mod bytes;
+pub mod gen;
+
+use aoc_parse::{one_of, Input, Parser};
+use log::debug;
+use num_traits::{CheckedAdd, CheckedMul, NumAssign};
+use std::{
+    any, fmt,
+    fs::File,
+    io::{self, BufRead},
+    path::Path,
+};
+
+/// Reads the lines of the file at `filename`.
+pub fn read_lines<P>(filename: P) -> Result<Vec<String>, io::Error>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(filename)?;
+    read_lines_from_reader(io::BufReader::new(file))
+}
+
+/// Reads the lines of `buf`. Fails if reading fails or a line is not UTF-8.
+pub fn read_lines_from_reader<R>(buf: R) -> Result<Vec<String>, io::Error>
+where
+    R: BufRead,
+{
+    let lines = buf.lines().collect::<Result<Vec<String>, io::Error>>()?;
+    debug!("Read {} lines", lines.len());
+    Ok(lines)
+}
+
+fn digits_value(line: &str) -> Result<u32, &'static str> {
+    let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+    match digits.next() {
+        Some(first) => Ok(first * 10 + digits.next_back().unwrap_or(first)),
+        None => Err("No digit found"),
+    }
+}
+
+// Why the answer does not fit into `T`.
+fn overflow<T>() -> String {
+    format!("answer overflows {}", any::type_name::<T>())
+}
+
+// Sums the values of the lines, failing on the first line without a value or
+// once the sum outgrows `T`. The line and the byte solvers share it, so they
+// fail the same way.
+pub(crate) fn sum_values<T, L, V, E>(
+    lines: impl IntoIterator<Item = L>,
+    value: V,
+) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+    V: Fn(L) -> Result<u32, E>,
+    E: fmt::Display,
+{
+    let mut sum = T::zero();
+    for (i, line) in lines.into_iter().enumerate() {
+        match value(line) {
+            Ok(value) => sum = sum.checked_add(&T::from(value)).ok_or_else(overflow::<T>)?,
+            Err(e) => return Err(format!("line {}: {}", i + 1, e)),
+        }
+    }
+    Ok(sum)
+}
+
+// The value of a line read by a streaming solver, or why reading it failed.
+fn read_value(
+    line: Result<String, io::Error>,
+    value: fn(&str) -> Result<u32, &'static str>,
+) -> Result<u32, String> {
+    match line {
+        Ok(line) => value(&line).map_err(String::from),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Sums the calibration values made of the first and the last numeric digit
+/// of every line. Fails on the first line without a numeric digit.
+pub fn sum_digits<T>(lines: &[String]) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    sum_values(lines, |line| digits_value(line))
+}
+
+/// Same as [`sum_digits`], reading `buf` one line at a time.
+///
+/// ```
+/// assert_eq!(day_01::sum_digits_streaming::<_, u64>("a1b2\n3c\n".as_bytes()), Ok(12 + 33));
+/// ```
+pub fn sum_digits_streaming<R, T>(buf: R) -> Result<T, String>
+where
+    R: BufRead,
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    sum_values(buf.lines(), |line| read_value(line, digits_value))
+}
+
+/// Solves part 1 for the input in the file at `filename`.
+pub fn puzzle_01<T, P>(filename: P) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+    P: AsRef<Path>,
+{
+    sum_digits(&read_lines(filename).map_err(|e| e.to_string())?)
+}
+
+const NUMBER_STRINGS: [&str; 18] = [
+    "1", "one", "2", "two", "3", "three", "4", "four", "5", "five", "6", "six", "7", "seven", "8",
+    "eight", "9", "nine",
+];
+const NUMBERS: [&str; 18] = [
+    "1", "1", "2", "2", "3", "3", "4", "4", "5", "5", "6", "6", "7", "7", "8", "8", "9", "9",
+];
+
+// The digit, written or spelled out, that starts right at `input`.
+fn digit_at(input: Input) -> Option<&'static str> {
+    match one_of(&NUMBER_STRINGS).parse(input) {
+        Ok((i, _)) => Some(NUMBERS[i]),
+        Err(_) => None,
+    }
+}
+
+/// The first and the last digit of `line`, numeric or spelled out, written
+/// as a two digit number. Spelled out digits may share letters.
+///
+/// ```
+/// assert_eq!(day_01::first_and_last_digit("eightwo"), Ok(String::from("82")));
+/// assert_eq!(day_01::first_and_last_digit("7pqrst"), Ok(String::from("77")));
+/// assert!(day_01::first_and_last_digit("abc").is_err());
+/// ```
+pub fn first_and_last_digit(line: &str) -> Result<String, &'static str> {
+    // Spelled out digits may overlap, e.g. "twone", so every char starts a
+    // new attempt.
+    let mut digits = line
+        .char_indices()
+        .filter_map(|(i, _)| digit_at(Input::new(&line[i..])));
+    match digits.next() {
+        Some(first) => Ok(format!("{}{}", first, digits.next_back().unwrap_or(first))),
+        None => Err("No digit found"),
+    }
+}
+
+fn digits_and_words_value(line: &str) -> Result<u32, &'static str> {
+    first_and_last_digit(line).map(|digits| {
+        digits
+            .bytes()
+            .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'))
+    })
+}
+
+/// Sums the calibration values of every line, counting spelled out digits
+/// as well. Fails on the first line without any digit.
+pub fn sum_digits_and_words<T>(lines: &[String]) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    sum_values(lines, |line| digits_and_words_value(line))
+}
+
+/// Same as [`sum_digits_and_words`], but reads one line at a time.
+pub fn sum_digits_and_words_streaming<R, T>(buf: R) -> Result<T, String>
+where
+    R: BufRead,
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    sum_values(buf.lines(), |line| read_value(line, digits_and_words_value))
+}
+
+/// Solves part 2 for the input in the file at `filename`.
+pub fn puzzle_02<T, P>(filename: P) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+    P: AsRef<Path>,
+{
+    sum_digits_and_words(&read_lines(filename).map_err(|e| e.to_string())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bytes, first_and_last_digit, gen, puzzle_01, puzzle_02, read_lines_from_reader, sum_digits,
+        sum_digits_and_words, sum_digits_and_words_streaming, sum_digits_streaming,
+    };
+    use aoc_examples::failing_reader;
+    use proptest::prelude::*;
+
+    #[test]
+    fn reading_errors_are_passed_on() {
+        let error = Err(String::from("line 2: reading failed"));
+        assert_eq!(
+            sum_digits_streaming::<_, u32>(failing_reader("1abc2\n")),
+            error
+        );
+        assert_eq!(
+            sum_digits_and_words_streaming::<_, u32>(failing_reader("1abc2\n")),
+            error
+        );
+        assert!(read_lines_from_reader(failing_reader("1abc2\n")).is_err());
+    }
+
+    #[test]
+    fn lines_without_a_digit_are_errors() {
+        let input = "1abc2\nabc\n";
+        let error = Err(String::from("line 2: No digit found"));
+        let lines = read_lines_from_reader(input.as_bytes()).unwrap();
+        assert_eq!(sum_digits::<u32>(&lines), error);
+        assert_eq!(sum_digits_and_words::<u32>(&lines), error);
+        assert_eq!(sum_digits_streaming::<_, u32>(input.as_bytes()), error);
+        assert_eq!(bytes::sum_digits::<u32>(input.as_bytes()), error);
+        assert_eq!(bytes::sum_digits_and_words::<u32>(input.as_bytes()), error);
+    }
+
+    aoc_examples::example_tests!(|path| puzzle_01::<u32, _>(path).unwrap(), |path| {
+        puzzle_02::<u32, _>(path).unwrap()
+    },);
+
+    proptest! {
+        #[test]
+        fn parser_never_panics(data in prop::collection::vec(any::<u8>(), 0..256)) {
+            for line in read_lines_from_reader(data.as_slice()).unwrap_or_default() {
+                let _ = first_and_last_digit(&line);
+            }
+        }
+
+        #[test]
+        fn streaming_matches_in_memory(size in 0usize..300, seed: u64) {
+            let input = gen::generate(size, seed);
+            let lines = read_lines_from_reader(input.as_bytes()).unwrap();
+            prop_assert_eq!(
+                sum_digits_streaming::<_, u64>(input.as_bytes()),
+                sum_digits::<u64>(&lines)
+            );
+            prop_assert_eq!(
+                sum_digits_and_words_streaming::<_, u64>(input.as_bytes()),
+                sum_digits_and_words::<u64>(&lines)
+            );
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use aoc_examples::bench_inputs;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_02::{bytes, gen, get_games, sum_possible_ids, sum_powers};
+use std::fs;
+
+// Generated inputs of these sizes, see `gen::generate`.
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+fn bench_day_02(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_02");
+    for (name, path) in bench_inputs("day_02", &SIZES, gen::generate) {
+        let games = get_games(&path).unwrap();
+        group.throughput(Throughput::Elements(games.len() as u64));
+        group.bench_with_input(BenchmarkId::new("get_games", &name), &path, |b, path| {
+            b.iter(|| get_games(path).unwrap())
+        });
+        group.bench_with_input(
+            BenchmarkId::new("sum_possible_ids", &name),
+            &games,
+            |b, games| b.iter(|| sum_possible_ids::<u64>(games, 14, 12, 13)),
+        );
+        group.bench_with_input(BenchmarkId::new("sum_powers", &name), &games, |b, games| {
+            b.iter(|| sum_powers::<u64>(games))
+        });
+    }
+    group.finish();
+}
+
+// `get_games` against `bytes::get_games`, both reading the file.
+fn bench_readers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_02/reader");
+    for (name, path) in bench_inputs("day_02", &SIZES, gen::generate) {
+        group.bench_with_input(BenchmarkId::new("lines", &name), &path, |b, path| {
+            b.iter(|| get_games(path).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("bytes", &name), &path, |b, path| {
+            b.iter(|| bytes::get_games(&fs::read(path).unwrap()).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_day_02, bench_readers);
+criterion_main!(benches);
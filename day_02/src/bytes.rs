@@ -0,0 +1,81 @@
+//! Parsing of the games from the whole input held as bytes. Errors name the
+//! line but not the column.
+
+use crate::{Game, COLOURS};
+use aoc_parse::bytes::{lines, number, skip_spaces};
+
+fn parse_game(line: &[u8]) -> Option<Game> {
+    let rest = skip_spaces(line.strip_prefix(b"Game")?);
+    let (id, rest) = number::<u32>(rest)?;
+    let mut rest = skip_spaces(skip_spaces(rest).strip_prefix(b":")?);
+    let mut max_counts = [None; 3];
+    loop {
+        // Draws may show no cubes at all.
+        if !rest.is_empty() && !rest.starts_with(b";") {
+            loop {
+                let (count, after) = number::<u32>(rest)?;
+                let after = skip_spaces(after);
+                let colour = COLOURS
+                    .iter()
+                    .position(|colour| after.starts_with(colour.as_bytes()))?;
+                max_counts[colour] = max_counts[colour].max(Some(count));
+                rest = skip_spaces(&after[COLOURS[colour].len()..]);
+                match rest.strip_prefix(b",") {
+                    Some(after) => rest = skip_spaces(after),
+                    None => break,
+                }
+            }
+        }
+        match rest.strip_prefix(b";") {
+            Some(after) => rest = skip_spaces(after),
+            None if rest.is_empty() => break,
+            None => return None,
+        }
+    }
+    match max_counts {
+        [Some(red), Some(green), Some(blue)] => Some(Game {
+            id,
+            blue,
+            red,
+            green,
+        }),
+        _ => None,
+    }
+}
+
+/// Same as [`crate::get_games`], but over the whole input at once.
+///
+/// ```
+/// let games = day_02::bytes::get_games(b"Game 7: 1 red, 2 green, 3 blue\n").unwrap();
+/// assert_eq!(games[0].id(), 7);
+/// assert_eq!(games[0].power::<u32>(), Some(6));
+/// ```
+pub fn get_games(input: &[u8]) -> Result<Vec<Game>, String> {
+    lines(input)
+        .enumerate()
+        .map(|(i, line)| match parse_game(line) {
+            Some(game) => Ok(game),
+            None => Err(format!("line {}: Invalid game", i + 1)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bytes, gen, get_games_from_reader, sum_possible_ids, sum_powers};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn bytes_match_lines(size in 0usize..100, seed: u64) {
+            let input = gen::generate(size, seed);
+            let games = get_games_from_reader(input.as_bytes()).unwrap();
+            let bytes_games = bytes::get_games(input.as_bytes()).unwrap();
+            prop_assert_eq!(
+                sum_possible_ids::<u64>(&bytes_games, 14, 12, 13),
+                sum_possible_ids::<u64>(&games, 14, 12, 13)
+            );
+            prop_assert_eq!(sum_powers::<u64>(&bytes_games), sum_powers::<u64>(&games));
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Random games for tests and benchmarks.
+
+use crate::COLOURS;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Generates `size` games of one to six draws each. Every colour shows up in
+/// at least one draw of a game.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut input = String::new();
+    for id in 1..=size {
+        let draw_count = rng.gen_range(1..=6);
+        let mut draws: Vec<Vec<&str>> = vec![Vec::new(); draw_count];
+        for colour in COLOURS {
+            draws[rng.gen_range(0..draw_count)].push(colour);
+        }
+        for draw in &mut draws {
+            for colour in COLOURS {
+                if !draw.contains(&colour) && rng.gen_bool(0.5) {
+                    draw.push(colour);
+                }
+            }
+            draw.shuffle(&mut rng);
+        }
+        let draws: Vec<String> = draws
+            .into_iter()
+            .map(|draw| {
+                draw.into_iter()
+                    .map(|colour| format!("{} {}", rng.gen_range(1..=20), colour))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            })
+            .collect();
+        input.push_str(&format!("Game {}: {}\n", id, draws.join("; ")));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use crate::{get_games_from_reader, sum_possible_ids, sum_powers};
+
+    #[test]
+    fn games_are_numbered_and_show_every_colour() {
+        let input = generate(500, 2);
+        assert_eq!(input, generate(500, 2));
+        // The parser rejects games that miss a colour.
+        let games = get_games_from_reader(input.as_bytes()).unwrap();
+        assert!(games.iter().map(|game| game.id()).eq(1..=500));
+        assert_eq!(sum_possible_ids::<u64>(&games, 14, 12, 13), Ok(11916));
+        assert_eq!(sum_powers::<u64>(&games), Ok(1247795));
+    }
+}
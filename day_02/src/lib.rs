@@ -0,0 +1,316 @@
+//! Day 2: Cube Conundrum. Every game draws sets of red, green and blue cubes
+//! from a bag. Part 1 sums the ids of the games possible with a given number
+//! of cubes, part 2 sums the powers of the fewest cubes every game needs.
+//!
+//! ```
+//! let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+//! Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+//! Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+//! ";
+//! let games = day_02::get_games_from_reader(input.as_bytes()).unwrap();
+//! assert_eq!(day_02::sum_possible_ids::<u32>(&games, 14, 12, 13), Ok(1 + 2));
+//! assert_eq!(day_02::sum_powers::<u32>(&games), Ok(48 + 12 + 1560));
+//! ```
+//!
+//! The power of a game multiplies three cube counts, so its sum outgrows a
+//! `u32` long before the sum of the ids does. Both sum into any `T` that
+//! converts from `u32`, and fail once the answer outgrows it.
+
+#![warn(missing_docs)]
+
+pub mod bytes;
+pub mod gen;
+
+use aoc_parse::{
+    labelled, map, number, one_of, optional, pair, parse_all, preceded, separated, tag, token,
+    Input, Parser,
+};
+use log::debug;
+use num_traits::{CheckedAdd, CheckedMul, NumAssign};
+use std::{
+    any, fmt,
+    fs::File,
+    io::{self, BufRead},
+    path::Path,
+};
+
+pub(crate) const COLOURS: [&str; 3] = ["red", "green", "blue"];
+
+/// A game, reduced to the most cubes of each colour any of its draws shows.
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    blue: u32,
+    red: u32,
+    green: u32,
+}
+
+/// Reads the games in the file at `filename`, one per line.
+pub fn get_games<P>(filename: P) -> Result<Vec<Game>, String>
+where
+    P: AsRef<Path>,
+{
+    let file = match File::open(filename) {
+        Ok(file) => file,
+        Err(_) => return Err(String::from("Failed opening file!")),
+    };
+    get_games_from_reader(io::BufReader::new(file))
+}
+
+// "Game 1: 3 blue, 4 red; 1 red, 2 green", as the id and the draws, with the
+// count and the index in `COLOURS` of every colour drawn.
+fn game<'a>() -> impl Parser<'a, (u32, Vec<Vec<(u32, usize)>>)> {
+    let cubes = pair(token(number::<u32>()), token(one_of(&COLOURS)));
+    // Draws may show no cubes at all.
+    let draw = map(
+        optional(separated(cubes, token(tag(",")))),
+        Option::unwrap_or_default,
+    );
+    pair(
+        labelled("Game", number::<u32>()),
+        preceded(token(tag(":")), separated(draw, token(tag(";")))),
+    )
+}
+
+fn parse_game(line: Input) -> Result<Game, String> {
+    let (id, draws) = parse_all(game(), line)?;
+    let mut max_counts = [None; 3];
+    for (count, colour) in draws.into_iter().flatten() {
+        max_counts[colour] = max_counts[colour].max(Some(count));
+    }
+    match max_counts {
+        [Some(red), Some(green), Some(blue)] => Ok(Game {
+            id,
+            blue,
+            red,
+            green,
+        }),
+        _ => Err(format!("{}: Some cube colours are missing", line.span())),
+    }
+}
+
+/// Parses one game per line as the lines are read. Errors name the line and
+/// column where the game stops making sense, or the line that failed to read.
+///
+/// ```
+/// let mut games = day_02::iter_games("Game 1: 2 red, 1 green, 3 blue\nGame 2: 4 pink\n".as_bytes());
+/// assert_eq!(games.next().unwrap().unwrap().id(), 1);
+/// assert!(games.next().unwrap().unwrap_err().starts_with("line 2"));
+/// ```
+pub fn iter_games<R>(buf: R) -> impl Iterator<Item = Result<Game, String>>
+where
+    R: BufRead,
+{
+    buf.lines().enumerate().map(|(i, line)| match line {
+        Ok(line) => parse_game(Input::at_line(&line, i + 1)),
+        Err(e) => Err(format!("line {}: {}", i + 1, e)),
+    })
+}
+
+/// Reads all games of `buf`, failing on the first one that does not parse.
+pub fn get_games_from_reader<R>(buf: R) -> Result<Vec<Game>, String>
+where
+    R: BufRead,
+{
+    iter_games(buf)
+        .collect::<Result<Vec<Game>, String>>()
+        .inspect(|games| debug!("Parsed {} games", games.len()))
+}
+
+impl Game {
+    /// The number of the game.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Whether the bag could hold the cubes of every draw of the game.
+    pub fn is_possible(&self, max_blue: u32, max_red: u32, max_green: u32) -> bool {
+        self.blue <= max_blue && self.red <= max_red && self.green <= max_green
+    }
+
+    /// The product of the fewest cubes of each colour the game needs, or
+    /// `None` if it does not fit into `T`.
+    pub fn power<T>(&self) -> Option<T>
+    where
+        T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+    {
+        T::from(self.blue)
+            .checked_mul(&T::from(self.red))?
+            .checked_mul(&T::from(self.green))
+    }
+}
+
+// A game only keeps the most cubes of each colour shown in any of its sets.
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Game {}: at most {} red, {} green, {} blue",
+            self.id, self.red, self.green, self.blue
+        )
+    }
+}
+
+// Why the answer does not fit into `T`.
+fn overflow<T>() -> String {
+    format!("answer overflows {}", any::type_name::<T>())
+}
+
+/// Sums the ids of the games that are possible with a bag of `max_blue`,
+/// `max_red` and `max_green` cubes. Fails if the sum does not fit into `T`.
+pub fn sum_possible_ids<T>(
+    games: &[Game],
+    max_blue: u32,
+    max_red: u32,
+    max_green: u32,
+) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    games
+        .iter()
+        .filter(|game| game.is_possible(max_blue, max_red, max_green))
+        .try_fold(T::zero(), |sum, game| sum.checked_add(&T::from(game.id)))
+        .ok_or_else(overflow::<T>)
+}
+
+/// Sums the powers of all games. Fails if a power or the sum does not fit
+/// into `T`.
+///
+/// ```
+/// let input = "Game 1: 2000 red, 2000 green, 2000 blue\n";
+/// let games = day_02::get_games_from_reader(input.as_bytes()).unwrap();
+/// assert_eq!(day_02::sum_powers::<u64>(&games), Ok(8_000_000_000));
+/// assert_eq!(
+///     day_02::sum_powers::<u32>(&games),
+///     Err(String::from("answer overflows u32"))
+/// );
+/// ```
+pub fn sum_powers<T>(games: &[Game]) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    games
+        .iter()
+        .try_fold(T::zero(), |sum, game| sum.checked_add(&game.power::<T>()?))
+        .ok_or_else(overflow::<T>)
+}
+
+/// Same as [`sum_possible_ids`], parsing `buf` one game at a time.
+pub fn sum_possible_ids_streaming<R, T>(
+    buf: R,
+    max_blue: u32,
+    max_red: u32,
+    max_green: u32,
+) -> Result<T, String>
+where
+    R: BufRead,
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    let mut sum = T::zero();
+    for game in iter_games(buf) {
+        let game = game?;
+        if game.is_possible(max_blue, max_red, max_green) {
+            sum = sum
+                .checked_add(&T::from(game.id))
+                .ok_or_else(overflow::<T>)?;
+        }
+    }
+    Ok(sum)
+}
+
+/// Same as [`sum_powers`], but parses one game at a time.
+pub fn sum_powers_streaming<R, T>(buf: R) -> Result<T, String>
+where
+    R: BufRead,
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    let mut sum = T::zero();
+    for game in iter_games(buf) {
+        let power = game?.power::<T>().ok_or_else(overflow::<T>)?;
+        sum = sum.checked_add(&power).ok_or_else(overflow::<T>)?;
+    }
+    Ok(sum)
+}
+
+/// Solves part 1 for the input in the file at `filename`.
+pub fn puzzle01<T, P>(filename: P, max_blue: u32, max_red: u32, max_green: u32) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+    P: AsRef<Path>,
+{
+    sum_possible_ids(&get_games(filename)?, max_blue, max_red, max_green)
+}
+
+/// Solves part 2 for the input in the file at `filename`.
+pub fn puzzle02<T, P>(filename: P) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+    P: AsRef<Path>,
+{
+    sum_powers(&get_games(filename)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        gen, get_games_from_reader, sum_possible_ids, sum_possible_ids_streaming, sum_powers,
+        sum_powers_streaming,
+    };
+    use aoc_examples::failing_reader;
+    use proptest::prelude::*;
+
+    aoc_examples::example_tests!(
+        |path| crate::puzzle01::<u32, _>(path, 14, 12, 13).unwrap(),
+        |path| { crate::puzzle02::<u32, _>(path).unwrap() }
+    );
+
+    #[test]
+    fn reading_errors_are_passed_on() {
+        let input = "Game 1: 3 blue, 4 red, 1 green\n";
+        let error = Err(String::from("line 2: reading failed"));
+        assert_eq!(sum_powers_streaming::<_, u32>(failing_reader(input)), error);
+        assert_eq!(
+            sum_possible_ids_streaming::<_, u32>(failing_reader(input), 14, 12, 13),
+            error
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_input() {
+        let input = "Game 1: 3 blue, 4 red, 1 green\nGame 2: 1 blue, 2 purple\n";
+        assert_eq!(
+            get_games_from_reader(input.as_bytes()).err(),
+            Some(String::from(
+                "line 2, column 19: expected one of red, green, blue, found 'p'"
+            ))
+        );
+        assert_eq!(
+            get_games_from_reader("Game 3: 1 blue; 2 red".as_bytes()).err(),
+            Some(String::from(
+                "line 1, column 1: Some cube colours are missing"
+            ))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parser_never_panics(input in "(Game [0-9]{1,12}: ([0-9]{1,12} (red|green|blue)[,;]? ?){0,6}\n?){0,5}") {
+            let _ = get_games_from_reader(input.as_bytes());
+        }
+
+        #[test]
+        fn streaming_matches_in_memory(size in 0usize..100, seed: u64) {
+            let input = gen::generate(size, seed);
+            let games = get_games_from_reader(input.as_bytes()).unwrap();
+            prop_assert_eq!(
+                sum_possible_ids_streaming::<_, u64>(input.as_bytes(), 14, 12, 13),
+                sum_possible_ids::<u64>(&games, 14, 12, 13)
+            );
+            prop_assert_eq!(
+                sum_powers_streaming::<_, u64>(input.as_bytes()),
+                sum_powers::<u64>(&games)
+            );
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_examples::bench_inputs;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day_03::{bytes, gen, get_neighbours, read, sum_gear_ratios, sum_part_numbers};
+use std::{fs, hint::black_box};
+
+// Widths of the generated square schematics, around the 140 of the real one.
+// The solvers compare every number with every symbol, so the grids stay small.
+const SIZES: [usize; 3] = [70, 140, 280];
+
+fn bench_read(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_03/read");
+    for (name, path) in bench_inputs("day_03", &SIZES, gen::generate) {
+        group.bench_with_input(BenchmarkId::from_parameter(&name), &path, |b, path| {
+            b.iter(|| read(path).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_get_neighbours(c: &mut Criterion) {
+    let (_, number_vec) = read("puzzle01_input").unwrap();
+    c.bench_function("day_03/get_neighbours", |b| {
+        b.iter(|| {
+            for number in &number_vec {
+                black_box(get_neighbours(&number.coordinates));
+            }
+        })
+    });
+}
+
+fn bench_solvers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_03");
+    group.sample_size(10);
+    for (name, path) in bench_inputs("day_03", &SIZES, gen::generate) {
+        let input = read(path).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("sum_part_numbers", &name),
+            &input,
+            |b, (symbol_vec, number_vec)| {
+                b.iter(|| sum_part_numbers::<u64>(symbol_vec, number_vec))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("sum_gear_ratios", &name),
+            &input,
+            |b, (symbol_vec, number_vec)| b.iter(|| sum_gear_ratios::<u64>(symbol_vec, number_vec)),
+        );
+    }
+    group.finish();
+}
+
+fn bench_readers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_03/reader");
+    for (name, path) in bench_inputs("day_03", &SIZES, gen::generate) {
+        group.bench_with_input(BenchmarkId::new("lines", &name), &path, |b, path| {
+            b.iter(|| read(path).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("bytes", &name), &path, |b, path| {
+            b.iter(|| bytes::read(&fs::read(path).unwrap()).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_read,
+    bench_get_neighbours,
+    bench_solvers,
+    bench_readers
+);
+criterion_main!(benches);
@@ -0,0 +1,84 @@
+//! Parsing of the schematic from the whole input held as bytes.
+
+use crate::{Number, Symbol};
+use aoc_parse::bytes::{lines, number};
+use log::debug;
+
+/// Same as [`crate::read`], but over the whole input at once. Columns count
+/// bytes, so the schematic has to be ASCII.
+///
+/// ```
+/// let (symbol_vec, number_vec) = day_03::bytes::read(b"7.\n.*\n").unwrap();
+/// assert_eq!(day_03::sum_part_numbers::<u32>(&symbol_vec, &number_vec), Ok(7));
+/// ```
+pub fn read(input: &[u8]) -> Result<(Vec<Symbol>, Vec<Number>), String> {
+    let mut symbol_vec = Vec::new();
+    let mut number_vec = Vec::new();
+
+    for (line_id, line) in lines(input).enumerate() {
+        let mut column_id = 0;
+        while column_id < line.len() {
+            let byte = line[column_id];
+            if byte.is_ascii_digit() {
+                let Some((value, rest)) = number::<u32>(&line[column_id..]) else {
+                    return Err(format!("line {}: Number too large", line_id + 1));
+                };
+                let end = line.len() - rest.len();
+                number_vec.push(Number {
+                    value,
+                    coordinates: (column_id..end).map(|x| (x, line_id)).collect(),
+                });
+                column_id = end;
+            } else if byte.is_ascii() {
+                if byte != b'.' {
+                    symbol_vec.push(Symbol {
+                        symbol: char::from(byte),
+                        coordinate: (column_id, line_id),
+                    });
+                }
+                column_id += 1;
+            } else {
+                return Err(format!("line {}: The schematic is not ASCII", line_id + 1));
+            }
+        }
+    }
+
+    debug!(
+        "Parsed {} symbols and {} numbers",
+        symbol_vec.len(),
+        number_vec.len()
+    );
+    Ok((symbol_vec, number_vec))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bytes, gen, read_from_reader, Number, Symbol};
+    use proptest::prelude::*;
+
+    fn symbols(symbol_vec: &[Symbol]) -> Vec<(char, (usize, usize))> {
+        symbol_vec
+            .iter()
+            .map(|symbol| (symbol.symbol, symbol.coordinate))
+            .collect()
+    }
+
+    fn numbers(number_vec: &[Number]) -> Vec<(u32, &[(usize, usize)])> {
+        number_vec
+            .iter()
+            .map(|number| (number.value, number.coordinates.as_slice()))
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn bytes_match_lines(size in 0usize..40, seed: u64) {
+            let input = gen::generate(size, seed);
+            let (symbol_vec, number_vec) = bytes::read(input.as_bytes()).unwrap();
+            let (expected_symbol_vec, expected_number_vec) =
+                read_from_reader(input.as_bytes()).unwrap();
+            prop_assert_eq!(symbols(&symbol_vec), symbols(&expected_symbol_vec));
+            prop_assert_eq!(numbers(&number_vec), numbers(&expected_number_vec));
+        }
+    }
+}
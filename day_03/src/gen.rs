@@ -0,0 +1,56 @@
+//! Random engine schematics for tests and benchmarks.
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '&', '-'];
+
+/// Generates a `size` x `size` engine schematic with numbers of one to three
+/// digits, scattered symbols and a bias towards `*` so gears occur.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut row = String::new();
+        while row.len() < size {
+            let previous_is_digit = row.ends_with(|c: char| c.is_ascii_digit());
+            let remaining = size - row.len();
+            match rng.gen_range(0..10) {
+                0..=2 if !previous_is_digit => {
+                    let length = rng.gen_range(1..=3).min(remaining);
+                    row.push_str(&rng.gen_range(1..=9).to_string());
+                    for _ in 1..length {
+                        row.push_str(&rng.gen_range(0..=9).to_string());
+                    }
+                }
+                3 => row.push('*'),
+                4 => row.push(*SYMBOLS.choose(&mut rng).unwrap()),
+                _ => row.push('.'),
+            }
+        }
+        input.push_str(&row);
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use crate::{read_from_reader, sum_gear_ratios, sum_part_numbers};
+
+    #[test]
+    fn schematic_is_square_and_has_gears() {
+        let input = generate(40, 3);
+        assert_eq!(input, generate(40, 3));
+        assert_eq!(input.lines().count(), 40);
+        assert!(input.lines().all(|line| line.len() == 40));
+        let (symbol_vec, number_vec) = read_from_reader(input.as_bytes()).unwrap();
+        assert!(number_vec.iter().all(|number| number.value < 1_000));
+        assert_eq!(sum_part_numbers::<u64>(&symbol_vec, &number_vec), Ok(53219));
+        assert_eq!(
+            sum_gear_ratios::<u64>(&symbol_vec, &number_vec),
+            Ok(4809973)
+        );
+    }
+}
@@ -0,0 +1,368 @@
+//! Day 3: Gear Ratios. The engine schematic is a grid of numbers and symbols.
+//! Part 1 sums the part numbers, the numbers next to a symbol, part 2 sums the
+//! gear ratios of the `*` next to exactly two numbers.
+//!
+//! Coordinates are `(column, row)`, both counted from 0. Diagonals count as
+//! next to each other.
+//!
+//! ```
+//! let schematic = "467..114..\n...*......\n..35..633.\n";
+//! let (symbol_vec, number_vec) = day_03::read_from_reader(schematic.as_bytes()).unwrap();
+//! assert_eq!(day_03::sum_part_numbers::<u32>(&symbol_vec, &number_vec), Ok(467 + 35));
+//! assert_eq!(day_03::sum_gear_ratios::<u32>(&symbol_vec, &number_vec), Ok(467 * 35));
+//! ```
+//!
+//! A gear ratio multiplies two part numbers, which makes part 2 the one to
+//! sum into a wider `T` than `u32`. Answers that outgrow `T` are errors.
+
+#![warn(missing_docs)]
+
+pub mod bytes;
+pub mod gen;
+pub mod render;
+
+use aoc_parse::{any_char, number, optional, spanned, Input, Parser};
+use log::debug;
+use num_traits::{CheckedAdd, CheckedMul, NumAssign};
+use std::{
+    any,
+    fs::File,
+    io::{self, BufRead},
+    path::Path,
+};
+
+/// A number of the schematic.
+#[derive(Debug, Clone)]
+pub struct Number {
+    /// The value of the number.
+    pub value: u32,
+    /// Every cell the digits of the number take, from left to right.
+    pub coordinates: Vec<(usize, usize)>,
+}
+
+/// Anything in the schematic that is neither a digit nor a `.`.
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    /// The symbol itself.
+    pub symbol: char,
+    /// The cell of the symbol.
+    pub coordinate: (usize, usize),
+}
+
+/// Reads the symbols and numbers of the schematic in the file at `filename`.
+pub fn read<P>(filename: P) -> Result<(Vec<Symbol>, Vec<Number>), String>
+where
+    P: AsRef<Path>,
+{
+    let file = match File::open(filename) {
+        Ok(file) => file,
+        Err(_) => return Err(String::from("Failed opening file")),
+    };
+    read_from_reader(io::BufReader::new(file))
+}
+
+/// Reads the symbols and numbers of the schematic in `buf`, in the order they
+/// appear row by row.
+///
+/// ```
+/// let (symbol_vec, number_vec) = day_03::read_from_reader("..12\n#...\n".as_bytes()).unwrap();
+/// assert_eq!(number_vec[0].value, 12);
+/// assert_eq!(number_vec[0].coordinates, [(2, 0), (3, 0)]);
+/// assert_eq!((symbol_vec[0].symbol, symbol_vec[0].coordinate), ('#', (0, 1)));
+/// ```
+pub fn read_from_reader<R>(buf: R) -> Result<(Vec<Symbol>, Vec<Number>), String>
+where
+    R: BufRead,
+{
+    let mut symbol_vec = Vec::new();
+    let mut number_vec = Vec::new();
+
+    for (line_id, line) in buf.lines().enumerate() {
+        let line = line.map_err(|e| format!("line {}: {}", line_id + 1, e))?;
+        read_row(&line, line_id, &mut symbol_vec, &mut number_vec)?;
+    }
+
+    debug!(
+        "Parsed {} symbols and {} numbers",
+        symbol_vec.len(),
+        number_vec.len()
+    );
+    Ok((symbol_vec, number_vec))
+}
+
+fn read_row(
+    line: &str,
+    line_id: usize,
+    symbol_vec: &mut Vec<Symbol>,
+    number_vec: &mut Vec<Number>,
+) -> Result<(), String> {
+    let mut input = Input::at_line(line, line_id + 1);
+    while !input.is_empty() {
+        if let (Some(number), rest) = optional(spanned(number::<u32>())).parse(input)? {
+            number_vec.push(Number {
+                value: number.value,
+                coordinates: (number.start.column - 1..number.end.column - 1)
+                    .map(|column_id| (column_id, line_id))
+                    .collect(),
+            });
+            input = rest;
+        } else {
+            let (c, rest) = any_char().parse(input)?;
+            if c != '.' {
+                symbol_vec.push(Symbol {
+                    symbol: c,
+                    coordinate: (input.span().column - 1, line_id),
+                });
+            }
+            input = rest;
+        }
+    }
+    Ok(())
+}
+
+fn get_neighbour(coordinate: &(usize, usize)) -> Vec<(usize, usize)> {
+    let mut neighbours = Vec::new();
+    for x in coordinate.0.saturating_sub(1)..=coordinate.0 + 1 {
+        for y in coordinate.1.saturating_sub(1)..=coordinate.1 + 1 {
+            neighbours.push((x, y));
+        }
+    }
+    neighbours
+}
+
+/// Every cell next to any of `coordinates`, the cells themselves included,
+/// sorted and without duplicates. Cells left of column 0 or above row 0 do
+/// not exist.
+///
+/// ```
+/// assert_eq!(
+///     day_03::get_neighbours(&[(0, 0), (1, 0)]),
+///     [(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1)]
+/// );
+/// ```
+pub fn get_neighbours(coordinates: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut neighbours = Vec::new();
+    for (x, y) in coordinates {
+        neighbours.extend(get_neighbour(&(*x, *y)));
+    }
+    neighbours.sort();
+    neighbours.dedup();
+    neighbours
+}
+
+// Why the answer does not fit into `T`.
+fn overflow<T>() -> String {
+    format!("answer overflows {}", any::type_name::<T>())
+}
+
+/// Sums the numbers that are next to any symbol. Fails if the sum does not
+/// fit into `T`.
+pub fn sum_part_numbers<T>(symbol_vec: &[Symbol], number_vec: &[Number]) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    let s_vec: Vec<(usize, usize)> = symbol_vec.iter().map(|symbol| symbol.coordinate).collect();
+
+    number_vec
+        .iter()
+        .filter(|number| {
+            get_neighbours(&number.coordinates)
+                .iter()
+                .any(|(x, y)| s_vec.contains(&(*x, *y)))
+        })
+        .try_fold(T::zero(), |sum, number| {
+            sum.checked_add(&T::from(number.value))
+        })
+        .ok_or_else(overflow::<T>)
+}
+
+/// Sums the products of the two numbers next to every `*` that is next to
+/// exactly two numbers. Fails if a product or the sum does not fit into `T`.
+pub fn sum_gear_ratios<T>(symbol_vec: &[Symbol], number_vec: &[Number]) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    let s_vec: Vec<(usize, usize)> = symbol_vec
+        .iter()
+        .filter(|symbol| symbol.symbol == '*')
+        .map(|symbol| symbol.coordinate)
+        .collect();
+
+    s_vec
+        .iter()
+        .filter_map(|coordinate| {
+            let vec: Vec<&Number> = number_vec
+                .iter()
+                .filter(|number| {
+                    get_neighbours(&number.coordinates)
+                        .iter()
+                        .any(|(x, y)| coordinate == &(*x, *y))
+                })
+                .collect();
+            if vec.len() == 2 {
+                Some(T::from(vec[0].value).checked_mul(&T::from(vec[1].value)))
+            } else {
+                None
+            }
+        })
+        .try_fold(T::zero(), |sum, ratio| sum.checked_add(&ratio?))
+        .ok_or_else(overflow::<T>)
+}
+
+#[derive(Default)]
+struct Row {
+    symbol_vec: Vec<Symbol>,
+    number_vec: Vec<Number>,
+}
+
+// Reads the schematic one row at a time and calls `f` with the previous, the
+// current and the next row for every row. Numbers and symbols only touch the
+// rows right above and below, so this window holds everything needed to solve
+// the current row. Stops at the first error of `f`.
+fn for_each_window<R, F>(buf: R, mut f: F) -> Result<(), String>
+where
+    R: BufRead,
+    F: FnMut(&[Row; 3]) -> Result<(), String>,
+{
+    let mut window: [Row; 3] = Default::default();
+    for (line_id, line) in buf.lines().enumerate() {
+        let line = line.map_err(|e| format!("line {}: {}", line_id + 1, e))?;
+        let mut row = Row::default();
+        read_row(&line, line_id, &mut row.symbol_vec, &mut row.number_vec)?;
+        window.rotate_left(1);
+        window[2] = row;
+        f(&window)?;
+    }
+    window.rotate_left(1);
+    window[2] = Row::default();
+    f(&window)
+}
+
+/// Same as [`sum_part_numbers`], holding only three rows of `buf` at a time.
+pub fn sum_part_numbers_streaming<R, T>(buf: R) -> Result<T, String>
+where
+    R: BufRead,
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    let mut sum = T::zero();
+    for_each_window(buf, |window| {
+        let symbol_vec: Vec<Symbol> = window
+            .iter()
+            .flat_map(|row| &row.symbol_vec)
+            .cloned()
+            .collect();
+        let row_sum = sum_part_numbers::<T>(&symbol_vec, &window[1].number_vec)?;
+        sum = sum.checked_add(&row_sum).ok_or_else(overflow::<T>)?;
+        Ok(())
+    })?;
+    Ok(sum)
+}
+
+/// Same as [`sum_gear_ratios`], holding only three rows of `buf` at a time.
+pub fn sum_gear_ratios_streaming<R, T>(buf: R) -> Result<T, String>
+where
+    R: BufRead,
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    let mut sum = T::zero();
+    for_each_window(buf, |window| {
+        let number_vec: Vec<Number> = window
+            .iter()
+            .flat_map(|row| &row.number_vec)
+            .cloned()
+            .collect();
+        let row_sum = sum_gear_ratios::<T>(&window[1].symbol_vec, &number_vec)?;
+        sum = sum.checked_add(&row_sum).ok_or_else(overflow::<T>)?;
+        Ok(())
+    })?;
+    Ok(sum)
+}
+
+/// Solves part 1 for the input in the file at `filename`.
+pub fn puzzle01<T, P>(filename: P) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+    P: AsRef<Path>,
+{
+    let (symbol_vec, number_vec) = read(filename)?;
+    sum_part_numbers(&symbol_vec, &number_vec)
+}
+
+/// Solves part 2 for the input in the file at `filename`.
+pub fn puzzle02<T, P>(filename: P) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+    P: AsRef<Path>,
+{
+    let (symbol_vec, number_vec) = read(filename)?;
+    sum_gear_ratios(&symbol_vec, &number_vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        gen, read_from_reader, sum_gear_ratios, sum_gear_ratios_streaming, sum_part_numbers,
+        sum_part_numbers_streaming,
+    };
+    use aoc_examples::failing_reader;
+    use proptest::prelude::*;
+
+    aoc_examples::example_tests!(|path| crate::puzzle01::<u32, _>(path).unwrap(), |path| {
+        crate::puzzle02::<u32, _>(path).unwrap()
+    },);
+
+    #[test]
+    fn reading_errors_are_passed_on() {
+        let input = "467..114..\n...*......\n";
+        let error = Err(String::from("line 3: reading failed"));
+        assert_eq!(
+            sum_part_numbers_streaming::<_, u32>(failing_reader(input)),
+            error
+        );
+        assert_eq!(
+            sum_gear_ratios_streaming::<_, u32>(failing_reader(input)),
+            error
+        );
+        assert_eq!(read_from_reader(failing_reader(input)).err(), error.err());
+    }
+
+    #[test]
+    fn streaming_example() {
+        let input = std::fs::read("puzzle01_input_test").unwrap();
+        assert_eq!(
+            sum_part_numbers_streaming::<_, u64>(input.as_slice()),
+            Ok(4361)
+        );
+        assert_eq!(
+            sum_gear_ratios_streaming::<_, u64>(input.as_slice()),
+            Ok(467835)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn parser_never_panics(input in "[0-9.*#\n]{0,256}") {
+            let _ = read_from_reader(input.as_bytes());
+        }
+
+        #[test]
+        fn overlong_numbers_are_errors(digits in "[1-9][0-9]{10,20}") {
+            let input = format!("..{}*", digits);
+            prop_assert!(read_from_reader(input.as_bytes()).is_err());
+        }
+
+        #[test]
+        fn streaming_matches_in_memory(size in 0usize..40, seed: u64) {
+            let input = gen::generate(size, seed);
+            let (symbol_vec, number_vec) = read_from_reader(input.as_bytes()).unwrap();
+            prop_assert_eq!(
+                sum_part_numbers_streaming::<_, u64>(input.as_bytes()),
+                sum_part_numbers::<u64>(&symbol_vec, &number_vec)
+            );
+            prop_assert_eq!(
+                sum_gear_ratios_streaming::<_, u64>(input.as_bytes()),
+                sum_gear_ratios::<u64>(&symbol_vec, &number_vec)
+            );
+        }
+    }
+}
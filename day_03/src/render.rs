@@ -0,0 +1,289 @@
+//! Pictures of the schematic, for the terminal and as SVG.
+
+use crate::{get_neighbours, Number, Symbol};
+use std::collections::{HashMap, HashSet};
+
+const PART_NUMBER: &str = "\x1b[1;32m";
+const OTHER_NUMBER: &str = "\x1b[2m";
+const GEAR: &str = "\x1b[1;33m";
+const SYMBOL: &str = "\x1b[36m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    PartNumber(char),
+    OtherNumber(char),
+    Gear,
+    Symbol(char),
+}
+
+struct Classified {
+    grid: Vec<Vec<Cell>>,
+    part_numbers: usize,
+    gears: usize,
+}
+
+// Places every number and symbol on a `width` x `height` grid, telling part
+// numbers from other numbers and gears from other symbols.
+fn classify(
+    symbol_vec: &[Symbol],
+    number_vec: &[Number],
+    width: usize,
+    height: usize,
+) -> Classified {
+    let symbol_coordinates: HashSet<(usize, usize)> =
+        symbol_vec.iter().map(|symbol| symbol.coordinate).collect();
+    let mut grid = vec![vec![Cell::Empty; width]; height];
+    let mut set = |(x, y): (usize, usize), cell: Cell| {
+        if x < width && y < height {
+            grid[y][x] = cell;
+        }
+    };
+
+    let mut adjacent_numbers: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut part_numbers = 0;
+    for number in number_vec {
+        let neighbours = get_neighbours(&number.coordinates);
+        for neighbour in &neighbours {
+            if symbol_coordinates.contains(neighbour) {
+                *adjacent_numbers.entry(*neighbour).or_default() += 1;
+            }
+        }
+        let is_part_number = neighbours
+            .iter()
+            .any(|neighbour| symbol_coordinates.contains(neighbour));
+        if is_part_number {
+            part_numbers += 1;
+        }
+        // Leading zeros are not kept in the value, so pad it back to the
+        // width the number had in the schematic.
+        let digits = format!("{:0>1$}", number.value, number.coordinates.len());
+        for (coordinate, digit) in number.coordinates.iter().zip(digits.chars()) {
+            set(
+                *coordinate,
+                if is_part_number {
+                    Cell::PartNumber(digit)
+                } else {
+                    Cell::OtherNumber(digit)
+                },
+            );
+        }
+    }
+
+    let mut gears = 0;
+    for symbol in symbol_vec {
+        let is_gear = symbol.symbol == '*' && adjacent_numbers.get(&symbol.coordinate) == Some(&2);
+        if is_gear {
+            gears += 1;
+            set(symbol.coordinate, Cell::Gear);
+        } else {
+            set(symbol.coordinate, Cell::Symbol(symbol.symbol));
+        }
+    }
+
+    Classified {
+        grid,
+        part_numbers,
+        gears,
+    }
+}
+
+/// Renders a `width` x `height` schematic followed by a legend. With `colour`
+/// part numbers are highlighted, numbers next to no symbol are dimmed and
+/// gears, the `*` next to exactly two numbers, are marked. Without `colour`
+/// the grid is printed as it was read, and every row with part numbers or
+/// gears is followed by a line with `^` under the digits of the part numbers
+/// and `G` under the gears.
+pub fn render(
+    symbol_vec: &[Symbol],
+    number_vec: &[Number],
+    width: usize,
+    height: usize,
+    colour: bool,
+) -> String {
+    let Classified {
+        grid,
+        part_numbers,
+        gears,
+    } = classify(symbol_vec, number_vec, width, height);
+
+    let paint = |code: &str, text: &str| {
+        if colour {
+            format!("{}{}{}", code, text, RESET)
+        } else {
+            text.to_string()
+        }
+    };
+    let mut output = String::new();
+    for row in grid {
+        for cell in &row {
+            output.push_str(&match cell {
+                Cell::Empty => String::from("."),
+                Cell::PartNumber(digit) => paint(PART_NUMBER, &digit.to_string()),
+                Cell::OtherNumber(digit) => paint(OTHER_NUMBER, &digit.to_string()),
+                Cell::Gear => paint(GEAR, "*"),
+                Cell::Symbol(symbol) => paint(SYMBOL, &symbol.to_string()),
+            });
+        }
+        output.push('\n');
+        if !colour {
+            let marks: String = row
+                .iter()
+                .map(|cell| match cell {
+                    Cell::PartNumber(_) => '^',
+                    Cell::Gear => 'G',
+                    _ => ' ',
+                })
+                .collect();
+            let marks = marks.trim_end();
+            if !marks.is_empty() {
+                output.push_str(marks);
+                output.push('\n');
+            }
+        }
+    }
+
+    output.push('\n');
+    if colour {
+        output.push_str(&format!(
+            "{} part number  {} other number  {} gear  {} symbol\n",
+            paint(PART_NUMBER, "123"),
+            paint(OTHER_NUMBER, "123"),
+            paint(GEAR, "*"),
+            paint(SYMBOL, "#")
+        ));
+    } else {
+        output.push_str("^ part number  G gear  unmarked numbers are next to no symbol\n");
+    }
+    output.push_str(&format!(
+        "{} part numbers, {} other numbers, {} gears\n",
+        part_numbers,
+        number_vec.len() - part_numbers,
+        gears
+    ));
+    output
+}
+
+const CELL_SIZE: usize = 14;
+const LEGEND_HEIGHT: usize = 28;
+
+/// Draws a `width` x `height` schematic as an SVG grid with the same marks as
+/// [`render`]: green part numbers, grey other numbers, yellow gears and blue
+/// other symbols, with the counts in a legend below.
+pub fn svg(symbol_vec: &[Symbol], number_vec: &[Number], width: usize, height: usize) -> String {
+    let Classified {
+        grid,
+        part_numbers,
+        gears,
+    } = classify(symbol_vec, number_vec, width, height);
+    let image_width = (width * CELL_SIZE).max(400);
+    let image_height = height * CELL_SIZE + LEGEND_HEIGHT;
+
+    let mut output = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\" font-family=\"monospace\" font-size=\"11\" text-anchor=\"middle\">\n",
+        image_width, image_height
+    );
+    output.push_str(&format!(
+        "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+        image_width, image_height
+    ));
+    for (y, row) in grid.iter().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            let (fill, text, text_fill) = match cell {
+                Cell::Empty => continue,
+                Cell::PartNumber(digit) => ("#c8e6c9", *digit, "#1b5e20"),
+                Cell::OtherNumber(digit) => ("#eeeeee", *digit, "#9e9e9e"),
+                Cell::Gear => ("#ffd54f", '*', "#000000"),
+                Cell::Symbol(symbol) => ("#b3e5fc", *symbol, "#01579b"),
+            };
+            output.push_str(&format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{size}\" height=\"{size}\" fill=\"{}\"/>",
+                x * CELL_SIZE,
+                y * CELL_SIZE,
+                fill,
+                size = CELL_SIZE
+            ));
+            output.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\" fill=\"{}\">{}</text>\n",
+                x * CELL_SIZE + CELL_SIZE / 2,
+                y * CELL_SIZE + CELL_SIZE - 3,
+                text_fill,
+                escape(text)
+            ));
+        }
+    }
+    output.push_str(&format!(
+        "<text x=\"8\" y=\"{}\" text-anchor=\"start\" font-size=\"13\">{} part numbers, {} other numbers, {} gears</text>\n",
+        height * CELL_SIZE + LEGEND_HEIGHT - 9,
+        part_numbers,
+        number_vec.len() - part_numbers,
+        gears
+    ));
+    output.push_str("</svg>\n");
+    output
+}
+
+fn escape(c: char) -> String {
+    match c {
+        '&' => String::from("&amp;"),
+        '<' => String::from("&lt;"),
+        '>' => String::from("&gt;"),
+        c => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render, svg, GEAR, OTHER_NUMBER, RESET};
+    use crate::read;
+    use std::fs;
+
+    #[test]
+    fn render_example() {
+        let input = fs::read_to_string("puzzle01_input_test").unwrap();
+        let (symbol_vec, number_vec) = read("puzzle01_input_test").unwrap();
+
+        let plain = render(&symbol_vec, &number_vec, 10, 10, false);
+        let (grid, legend) = plain.split_once("\n\n").unwrap();
+        let rows: Vec<&str> = grid.lines().filter(|line| line.contains('.')).collect();
+        assert_eq!(rows, input.lines().collect::<Vec<&str>>());
+        assert_eq!(
+            grid.lines()
+                .filter(|line| !line.contains('.'))
+                .collect::<Vec<&str>>(),
+            [
+                "^^^",
+                "   G",
+                "  ^^  ^^^",
+                "^^^",
+                "  ^^^",
+                "      ^^^",
+                "     G",
+                " ^^^ ^^^"
+            ]
+        );
+        assert_eq!(
+            legend,
+            "^ part number  G gear  unmarked numbers are next to no symbol\n\
+             8 part numbers, 2 other numbers, 2 gears\n"
+        );
+
+        let coloured = render(&symbol_vec, &number_vec, 10, 10, true);
+        assert_eq!(coloured.matches(&format!("{}*{}", GEAR, RESET)).count(), 3);
+        for digit in ["1", "4", "5", "8"] {
+            assert!(coloured.contains(&format!("{}{}{}", OTHER_NUMBER, digit, RESET)));
+        }
+    }
+
+    #[test]
+    fn svg_example() {
+        let (symbol_vec, number_vec) = read("puzzle01_input_test").unwrap();
+        let svg = svg(&symbol_vec, &number_vec, 10, 10);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("fill=\"#ffd54f\"").count(), 2);
+        assert_eq!(svg.matches("fill=\"#eeeeee\"").count(), 5);
+        assert!(svg.contains("8 part numbers, 2 other numbers, 2 gears"));
+    }
+}
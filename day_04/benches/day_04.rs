@@ -0,0 +1,90 @@
+use aoc_examples::bench_inputs;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_04::{
+    bytes, count_cards, count_cards_naive, gen, get_match_counts, get_match_counts_from_reader,
+    sum_points,
+};
+use num_bigint::BigUint;
+use std::fs;
+
+// Generated inputs of these sizes, see `gen::generate`.
+const SIZES: [usize; 3] = [200, 2_000, 20_000];
+
+fn bench_parse_and_score(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_04");
+    for (name, path) in bench_inputs("day_04", &SIZES, gen::generate) {
+        let match_counts = get_match_counts(&path).unwrap();
+        group.throughput(Throughput::Elements(match_counts.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::new("get_match_counts", &name),
+            &path,
+            |b, path| b.iter(|| get_match_counts(path).unwrap()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("sum_points", &name),
+            &match_counts,
+            |b, match_counts| b.iter(|| sum_points::<u64>(match_counts)),
+        );
+    }
+    group.finish();
+}
+
+fn bench_cascade(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_04/count_cards");
+    for (name, path) in bench_inputs("day_04", &SIZES, gen::generate) {
+        let match_counts = get_match_counts(path).unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&name),
+            &match_counts,
+            |b, match_counts| b.iter(|| count_cards::<u64>(match_counts)),
+        );
+    }
+    group.finish();
+
+    // count_cards_naive visits every won copy, which grows with the square of
+    // the number of cards, so only the smaller inputs are measured.
+    let mut group = c.benchmark_group("day_04/count_cards_naive");
+    group.sample_size(10);
+    for (name, path) in bench_inputs("day_04", &SIZES[..2], gen::generate) {
+        let match_counts = get_match_counts(path).unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&name),
+            &match_counts,
+            |b, match_counts| b.iter(|| count_cards_naive(match_counts)),
+        );
+    }
+    group.finish();
+
+    // The same cascade counted in wider integers, to see what the width of
+    // the answer costs.
+    let mut group = c.benchmark_group("day_04/count_cards_width");
+    let match_counts = get_match_counts_from_reader(gen::generate(SIZES[2], 0).as_bytes()).unwrap();
+    group.bench_with_input("u64", &match_counts, |b, match_counts| {
+        b.iter(|| count_cards::<u64>(match_counts))
+    });
+    group.bench_with_input("u128", &match_counts, |b, match_counts| {
+        b.iter(|| count_cards::<u128>(match_counts))
+    });
+    group.bench_with_input("big", &match_counts, |b, match_counts| {
+        b.iter(|| count_cards::<BigUint>(match_counts))
+    });
+    group.finish();
+}
+
+// The per-line reader allocates a String per card, the byte reader shares
+// one buffer for the winning numbers of all cards.
+fn bench_readers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_04/reader");
+    for (name, path) in bench_inputs("day_04", &SIZES, gen::generate) {
+        group.bench_with_input(BenchmarkId::new("lines", &name), &path, |b, path| {
+            b.iter(|| get_match_counts(path).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("bytes", &name), &path, |b, path| {
+            b.iter(|| bytes::get_match_counts(&fs::read(path).unwrap()).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse_and_score, bench_cascade, bench_readers);
+criterion_main!(benches);
@@ -0,0 +1,78 @@
+//! Match counting from the whole input held as bytes.
+
+use aoc_parse::bytes::{lines, number, skip_spaces};
+use log::debug;
+
+// Counts the matches of one card, keeping its winning numbers in
+// `winning_numbers` so that the buffer is shared by all cards.
+fn match_count(line: &[u8], winning_numbers: &mut Vec<u32>) -> Option<u32> {
+    let rest = skip_spaces(line.strip_prefix(b"Card")?);
+    let (_, rest) = number::<u32>(rest)?;
+    let mut rest = skip_spaces(skip_spaces(rest).strip_prefix(b":")?);
+
+    winning_numbers.clear();
+    while let Some((winning_number, after)) = number::<u32>(rest) {
+        winning_numbers.push(winning_number);
+        rest = skip_spaces(after);
+    }
+    rest = skip_spaces(rest.strip_prefix(b"|")?);
+
+    let mut count = 0;
+    while let Some((number, after)) = number::<u32>(rest) {
+        if winning_numbers.contains(&number) {
+            count += 1;
+        }
+        rest = skip_spaces(after);
+    }
+    if rest.is_empty() {
+        Some(count)
+    } else {
+        None
+    }
+}
+
+/// Same as [`crate::get_match_counts_from_reader`], but over the whole input
+/// at once. Errors name the line but not the column.
+///
+/// ```
+/// let input = b"Card 1: 1 2 | 2 1 3\nCard 2: 4 | 5\n";
+/// assert_eq!(day_04::bytes::get_match_counts(input), Ok(vec![2, 0]));
+/// assert!(day_04::bytes::get_match_counts(b"not a card\n").is_err());
+/// ```
+pub fn get_match_counts(input: &[u8]) -> Result<Vec<u32>, String> {
+    let mut winning_numbers = Vec::new();
+    lines(input)
+        .enumerate()
+        .map(|(i, line)| match match_count(line, &mut winning_numbers) {
+            Some(count) => Ok(count),
+            None => Err(format!("line {}: Invalid card", i + 1)),
+        })
+        .collect::<Result<Vec<u32>, String>>()
+        .inspect(|match_counts| debug!("Parsed {} cards", match_counts.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bytes, gen, get_match_counts_from_reader};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn bytes_match_lines(size in 0usize..200, seed: u64) {
+            let input = gen::generate_with_matches(size, seed, 10);
+            prop_assert_eq!(
+                bytes::get_match_counts(input.as_bytes()),
+                get_match_counts_from_reader(input.as_bytes())
+            );
+        }
+
+        // The readers fail on the same cards, with errors that differ.
+        #[test]
+        fn bytes_match_lines_on_malformed_cards(input in "(Card +[0-9]{1,3}: [0-9 ]{0,30}\\|[0-9 ]{0,30}\n?){0,5}") {
+            prop_assert_eq!(
+                bytes::get_match_counts(input.as_bytes()).ok(),
+                get_match_counts_from_reader(input.as_bytes()).ok()
+            );
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Random scratchcards for tests and benchmarks.
+
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const WINNING_COUNT: usize = 10;
+const OWN_COUNT: usize = 25;
+
+/// Default longest match chain of [`generate`]. Every card wins at most this
+/// many of the following cards, which keeps the number of copies in part 2
+/// growing slowly.
+pub const DEFAULT_MAX_MATCHES: usize = 2;
+
+/// Generates `size` scratchcards with up to [`DEFAULT_MAX_MATCHES`] matches.
+pub fn generate(size: usize, seed: u64) -> String {
+    generate_with_matches(size, seed, DEFAULT_MAX_MATCHES)
+}
+
+/// Generates `size` scratchcards with 10 winning and 25 own numbers, where
+/// every card has between zero and `max_matches` matches. Like the real
+/// puzzle, no card wins copies of cards past the end of the table.
+pub fn generate_with_matches(size: usize, seed: u64, max_matches: usize) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut input = String::new();
+    for id in 1..=size {
+        let mut numbers: Vec<u32> = (1..100).collect();
+        numbers.shuffle(&mut rng);
+        let (winning, rest) = numbers.split_at(WINNING_COUNT);
+
+        let matches = rng.gen_range(0..=max_matches.min(WINNING_COUNT).min(size - id));
+        let mut own: Vec<u32> = winning[..matches].to_vec();
+        own.extend_from_slice(&rest[..OWN_COUNT - matches]);
+        own.shuffle(&mut rng);
+
+        let format = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        input.push_str(&format!(
+            "Card {:>3}: {} | {}\n",
+            id,
+            format(winning),
+            format(&own)
+        ));
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate, generate_with_matches};
+    use crate::{count_cards, get_match_counts_from_reader, sum_points};
+
+    #[test]
+    fn matches_stay_below_the_default_chain() {
+        let input = generate(500, 4);
+        assert_eq!(input, generate(500, 4));
+        let match_counts = get_match_counts_from_reader(input.as_bytes()).unwrap();
+        assert_eq!(match_counts.len(), 500);
+        assert!(match_counts.iter().all(|count| *count <= 2));
+        assert_eq!(sum_points::<u64>(&match_counts), Ok(472));
+        // Chains of at most two keep the copies growing linearly.
+        assert_eq!(count_cards::<u64>(&match_counts), Ok(3776));
+    }
+
+    #[test]
+    fn match_chains_stay_within_the_table() {
+        let match_counts =
+            get_match_counts_from_reader(generate_with_matches(20, 5, 10).as_bytes()).unwrap();
+        for (i, count) in match_counts.iter().enumerate() {
+            assert!(i + (*count as usize) < match_counts.len());
+        }
+    }
+}
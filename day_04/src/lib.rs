@@ -0,0 +1,296 @@
+//! Day 4: Scratchcards. Every card has winning numbers and numbers you
+//! have, and all that matters about a card is how many of them match. Part 1
+//! sums the points of the cards, part 2 counts the cards once every card has
+//! won copies of the cards after it.
+//!
+//! ```
+//! let cards = "Card 1: 41 48 83 | 83 86 48 17
+//! Card 2: 13 32 20 | 61 30 68 32
+//! Card 3:  1 21 53 | 69 82 63 72
+//! ";
+//! let match_counts = day_04::get_match_counts_from_reader(cards.as_bytes()).unwrap();
+//! assert_eq!(match_counts, [2, 1, 0]);
+//! assert_eq!(day_04::sum_points::<u32>(&match_counts), Ok(2 + 1));
+//! assert_eq!(day_04::count_cards::<u32>(&match_counts), Ok(1 + 2 + 4));
+//! ```
+//!
+//! The solvers count into any integer type `T` that converts from `u32`, and
+//! fail once the answer outgrows it. The cards won grow exponentially with
+//! the matches, so long inputs need a `num_bigint::BigUint`.
+
+#![warn(missing_docs)]
+
+pub mod bytes;
+pub mod gen;
+
+use aoc_parse::{labelled, many, number, pair, parse_all, preceded, tag, token, Input, Parser};
+use log::debug;
+use num_traits::{CheckedAdd, CheckedMul, NumAssign};
+use std::{
+    any,
+    collections::HashMap,
+    fs::File,
+    io::{self, BufRead},
+    path::Path,
+};
+
+/// Reads the match counts of the cards in the file at `filename`.
+pub fn get_match_counts<P>(filename: P) -> Result<Vec<u32>, String>
+where
+    P: AsRef<Path>,
+{
+    let file = match File::open(filename) {
+        Ok(file) => file,
+        Err(e) => return Err(format!("Failed opening file: {}", e)),
+    };
+    get_match_counts_from_reader(io::BufReader::new(file))
+}
+
+fn card<'a>() -> impl Parser<'a, (Vec<u32>, Vec<u32>)> {
+    let numbers = || many(token(number::<u32>()));
+    preceded(
+        pair(labelled("Card", number::<u32>()), token(tag(":"))),
+        pair(numbers(), preceded(token(tag("|")), numbers())),
+    )
+}
+
+/// Counts the matches of one card per line as the lines are read. Errors name
+/// the line and column where the card stops making sense, or the line that
+/// failed to read.
+///
+/// ```
+/// let mut counts = day_04::iter_match_counts("Card 1: 1 2 | 2 3\nCard 2: 4 5 6\n".as_bytes());
+/// assert_eq!(counts.next(), Some(Ok(1)));
+/// assert!(counts.next().unwrap().unwrap_err().starts_with("line 2"));
+/// ```
+pub fn iter_match_counts<R>(buf: R) -> impl Iterator<Item = Result<u32, String>>
+where
+    R: BufRead,
+{
+    buf.lines().enumerate().map(|(i, line)| {
+        let line = line.map_err(|e| format!("line {}: {}", i + 1, e))?;
+        let (winning_numbers, numbers) = parse_all(card(), Input::at_line(&line, i + 1))?;
+        Ok(numbers
+            .iter()
+            .filter(|number| winning_numbers.contains(number))
+            .count() as u32)
+    })
+}
+
+/// The match counts of all cards in `buf`, in the order of the cards. Fails on
+/// the first line that is not a card.
+pub fn get_match_counts_from_reader<R>(buf: R) -> Result<Vec<u32>, String>
+where
+    R: BufRead,
+{
+    iter_match_counts(buf)
+        .collect::<Result<Vec<u32>, String>>()
+        .inspect(|match_counts| debug!("Parsed {} cards", match_counts.len()))
+}
+
+// Why the answer does not fit into `T`.
+fn overflow<T>() -> String {
+    format!("answer overflows {}", any::type_name::<T>())
+}
+
+fn points<T>(count: u32) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    if count == 0 {
+        return Ok(T::zero());
+    }
+    let mut points = T::one();
+    for _ in 1..count {
+        points = points.checked_mul(&T::from(2)).ok_or_else(overflow::<T>)?;
+    }
+    Ok(points)
+}
+
+/// Sums the points of the cards: 1 for the first match, doubled for every
+/// further match. Fails if the points do not fit into `T`.
+///
+/// ```
+/// assert_eq!(day_04::sum_points::<u32>(&[0, 1, 4]), Ok(1 + 8));
+/// assert!(day_04::sum_points::<u32>(&[33]).is_err());
+/// ```
+pub fn sum_points<T>(match_counts: &[u32]) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    match_counts.iter().try_fold(T::zero(), |sum, count| {
+        sum.checked_add(&points::<T>(*count)?)
+            .ok_or_else(overflow::<T>)
+    })
+}
+
+/// Same as [`sum_points`], scoring `buf` one card at a time.
+pub fn sum_points_streaming<R, T>(buf: R) -> Result<T, String>
+where
+    R: BufRead,
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+{
+    let mut sum = T::zero();
+    for match_count in iter_match_counts(buf) {
+        let points = points::<T>(match_count?)?;
+        sum = sum.checked_add(&points).ok_or_else(overflow::<T>)?;
+    }
+    Ok(sum)
+}
+
+/// Counts the original cards and all copies won, where a card with `n`
+/// matches wins a copy of each of the `n` cards after it. Matches past the
+/// last card win nothing. Fails if the cards do not fit into `T`.
+pub fn count_cards<T>(match_counts: &[u32]) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul + Clone,
+{
+    let mut copies = vec![T::one(); match_counts.len()];
+    for (i, count) in match_counts.iter().enumerate() {
+        let last = match_counts.len().min(i + 1 + *count as usize);
+        for j in i + 1..last {
+            copies[j] = copies[j]
+                .checked_add(&copies[i])
+                .ok_or_else(overflow::<T>)?;
+        }
+    }
+    copies
+        .into_iter()
+        .try_fold(T::zero(), |sum, copies| sum.checked_add(&copies))
+        .ok_or_else(overflow::<T>)
+}
+
+/// Same as [`count_cards`], but simulates every won copy one by one. Kept as
+/// the reference for [`count_cards`], it takes time in the number of cards
+/// won.
+pub fn count_cards_naive(match_counts: &[u32]) -> u32 {
+    let mut lines_count_vec: Vec<(usize, u32)> = match_counts.iter().copied().enumerate().collect();
+
+    let mut hm: HashMap<usize, u32> = HashMap::new();
+    for (i, v) in &lines_count_vec {
+        hm.insert(*i, *v);
+    }
+
+    let mut sum = 0;
+    while let Some((mut i, mut v)) = lines_count_vec.pop() {
+        sum += 1;
+        while 0 < v {
+            v -= 1;
+            i += 1;
+            match hm.get(&i) {
+                Some(u) => {
+                    lines_count_vec.push((i, *u));
+                }
+                None => break,
+            }
+        }
+    }
+
+    sum
+}
+
+/// Solves part 1 for the input in the file at `filename`.
+pub fn puzzle01<T, P>(filename: P) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul,
+    P: AsRef<Path>,
+{
+    sum_points(&get_match_counts(filename)?)
+}
+
+/// Solves part 2 for the input in the file at `filename`.
+pub fn puzzle02<T, P>(filename: P) -> Result<T, String>
+where
+    T: NumAssign + From<u32> + CheckedAdd + CheckedMul + Clone,
+    P: AsRef<Path>,
+{
+    count_cards(&get_match_counts(filename)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        count_cards, count_cards_naive, gen, get_match_counts_from_reader, sum_points,
+        sum_points_streaming,
+    };
+    use aoc_examples::failing_reader;
+    use num_bigint::BigUint;
+    use proptest::prelude::*;
+
+    aoc_examples::example_tests!(|path| crate::puzzle01::<u32, _>(path).unwrap(), |path| {
+        crate::puzzle02::<u32, _>(path).unwrap()
+    });
+
+    #[test]
+    fn bad_cards_are_errors() {
+        let input = "Card 1: 41 48 | 83 41\nCard 2 13 32 | 17\n";
+        let error = String::from("line 2, column 7: expected ':', found ' '");
+        assert_eq!(
+            get_match_counts_from_reader(input.as_bytes()),
+            Err(error.clone())
+        );
+        assert_eq!(sum_points_streaming::<_, u32>(input.as_bytes()), Err(error));
+        assert_eq!(
+            crate::bytes::get_match_counts(input.as_bytes()),
+            Err(String::from("line 2: Invalid card"))
+        );
+        assert_eq!(
+            sum_points_streaming::<_, u32>(failing_reader("Card 1: 1 | 1\n")),
+            Err(String::from("line 2: reading failed"))
+        );
+    }
+
+    #[test]
+    fn cascades_past_u128() {
+        // Every card wins a copy of all cards after it, so the copies double
+        // with every card.
+        let match_counts: Vec<u32> = (0..130).rev().collect();
+        assert_eq!(
+            count_cards::<BigUint>(&match_counts),
+            Ok((BigUint::from(1u32) << 130) - 1u32)
+        );
+        assert_eq!(
+            sum_points::<BigUint>(&match_counts[..1]),
+            Ok(BigUint::from(1u32) << 128)
+        );
+    }
+
+    #[test]
+    fn generated_input_overflows_u32() {
+        let input = gen::generate_with_matches(200, 7, 10);
+        let match_counts = get_match_counts_from_reader(input.as_bytes()).unwrap();
+        let error = Err(String::from("answer overflows u32"));
+        assert_eq!(count_cards::<u32>(&match_counts), error);
+        assert!(count_cards::<BigUint>(&match_counts).unwrap() > BigUint::from(u32::MAX));
+        assert!(sum_points::<u32>(&match_counts).is_ok());
+    }
+
+    proptest! {
+        #[test]
+        fn count_cards_matches_naive(match_counts in prop::collection::vec(0u32..=3, 0..24)) {
+            prop_assert_eq!(count_cards::<u32>(&match_counts), Ok(count_cards_naive(&match_counts)));
+        }
+
+        #[test]
+        fn count_cards_matches_naive_on_generated_input(size in 0usize..200, seed: u64) {
+            let match_counts =
+                get_match_counts_from_reader(gen::generate(size, seed).as_bytes()).unwrap();
+            prop_assert_eq!(count_cards::<u32>(&match_counts), Ok(count_cards_naive(&match_counts)));
+        }
+
+        #[test]
+        fn streaming_matches_in_memory(size in 0usize..200, seed: u64) {
+            let input = gen::generate_with_matches(size, seed, 10);
+            let match_counts = get_match_counts_from_reader(input.as_bytes()).unwrap();
+            prop_assert_eq!(
+                sum_points_streaming::<_, u64>(input.as_bytes()),
+                sum_points::<u64>(&match_counts)
+            );
+        }
+
+        #[test]
+        fn parser_never_panics(input in "(Card +[0-9]{1,3}: [0-9 ]{0,30}\\|[0-9 ]{0,30}\n?){0,5}") {
+            let _ = get_match_counts_from_reader(input.as_bytes());
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_examples::bench_inputs;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use day_05::{bytes, gen, lowest_location, lowest_location_of_ranges, parse_almanac};
+use std::{fs, hint::black_box};
+
+// Seed ranges and entries per map of the generated almanacs, see
+// `gen::generate`. The real almanac has 10 seed ranges and a few dozen
+// entries per map.
+const SIZES: [usize; 3] = [10, 100, 1_000];
+
+fn bench_parse(c: &mut Criterion) {
+    c.bench_function("day_05/parse_almanac", |b| {
+        b.iter(|| parse_almanac("puzzle01_input").unwrap())
+    });
+}
+
+fn bench_mapping(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_05/lowest_location");
+    for (name, path) in bench_inputs("day_05", &SIZES, gen::generate) {
+        let almanac = parse_almanac(path).unwrap();
+        group.throughput(Throughput::Elements(almanac.seeds().len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&name),
+            &almanac,
+            |b, almanac| b.iter(|| lowest_location(almanac).unwrap()),
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("day_05/lowest_location_of_ranges");
+    for (name, path) in bench_inputs("day_05", &SIZES, gen::generate) {
+        let almanac = parse_almanac(path).unwrap();
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&name),
+            &almanac,
+            |b, almanac| b.iter(|| lowest_location_of_ranges(almanac).unwrap()),
+        );
+    }
+    group.finish();
+
+    let almanac = parse_almanac("puzzle01_input").unwrap();
+    c.bench_function("day_05/location", |b| {
+        b.iter(|| almanac.location(black_box(3489262449)).unwrap())
+    });
+}
+
+fn bench_readers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day_05/reader");
+    for (name, path) in bench_inputs("day_05", &SIZES, gen::generate) {
+        group.bench_with_input(BenchmarkId::new("lines", &name), &path, |b, path| {
+            b.iter(|| parse_almanac(path).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("bytes", &name), &path, |b, path| {
+            b.iter(|| bytes::parse_almanac(&fs::read(path).unwrap()).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_mapping, bench_readers);
+criterion_main!(benches);
@@ -0,0 +1,131 @@
+//! Parsing of the almanac from the whole input held as bytes. Errors name
+//! the line but not the column.
+
+use crate::{Almanac, Mapping, SECTIONS};
+use aoc_parse::bytes::{number, sections, skip_spaces};
+
+// All numbers of `line`, or None if anything else is on it.
+fn numbers(mut line: &[u8], numbers: &mut Vec<u64>) -> Option<()> {
+    while !line.is_empty() {
+        let (value, rest) = number::<u64>(line)?;
+        numbers.push(value);
+        line = skip_spaces(rest);
+    }
+    Some(())
+}
+
+fn parse_mapping_section(
+    section_line: &str,
+    map_vec_name: &str,
+    lines: &[(usize, &[u8])],
+) -> Result<Vec<Mapping>, String> {
+    let Some(((line_number, header), number_lines)) = lines.split_first() else {
+        return Ok(Vec::new());
+    };
+    if header.trim_ascii_end() != section_line.as_bytes() {
+        return Err(format!(
+            "line {}: {}: Expected '{}'",
+            line_number, map_vec_name, section_line
+        ));
+    }
+    let mut map_vec = Vec::with_capacity(number_lines.len());
+    let mut values = Vec::with_capacity(3);
+    for (line_number, line) in number_lines {
+        values.clear();
+        let mapping = match numbers(line, &mut values) {
+            Some(()) if values.len() == 3 => Mapping::new(values[0], values[1], values[2]),
+            _ => {
+                return Err(format!(
+                    "line {}: {}: Expected three numbers",
+                    line_number, map_vec_name
+                ))
+            }
+        };
+        match mapping {
+            Some(mapping) => map_vec.push(mapping),
+            None => {
+                return Err(format!(
+                    "line {}: {}: Range exceeds the u64 limit",
+                    line_number, map_vec_name
+                ))
+            }
+        }
+    }
+    Ok(map_vec)
+}
+
+/// Same as [`crate::parse_almanac_from_reader`], but over the whole input at
+/// once.
+pub fn parse_almanac(input: &[u8]) -> Result<Almanac, String> {
+    let mut sections = sections(input).into_iter();
+
+    let Some(seed_section) = sections.next() else {
+        return Err(String::from("Seed line does not exist"));
+    };
+    let mut seed_vec = Vec::new();
+    let seeds = seed_section[0].1.strip_prefix(b"seeds:");
+    if seeds
+        .and_then(|seeds| numbers(skip_spaces(seeds), &mut seed_vec))
+        .is_none()
+    {
+        return Err(String::from("line 1: Expected the seeds"));
+    }
+    if let Some((line_number, _)) = seed_section.get(1) {
+        return Err(format!(
+            "line {}: Expected a blank line after the seeds",
+            line_number
+        ));
+    }
+
+    let mut mapping_routine_vec = Vec::new();
+    for (section_line, map_vec_name) in SECTIONS {
+        let Some(lines) = sections.next() else {
+            return Err(format!("{} section is missing", map_vec_name));
+        };
+        mapping_routine_vec.push((
+            parse_mapping_section(section_line, map_vec_name, &lines)?,
+            map_vec_name,
+        ));
+    }
+    if let Some(lines) = sections.next() {
+        return Err(format!(
+            "line {}: Expected the end of the almanac after the last map",
+            lines[0].0
+        ));
+    }
+
+    Ok(Almanac {
+        seed_vec,
+        mapping_routine_vec,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bytes, gen, lowest_location, lowest_location_of_ranges, parse_almanac_from_reader,
+    };
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn bytes_match_lines(size in 0usize..50, seed: u64) {
+            let input = gen::generate(size, seed);
+            let almanac = parse_almanac_from_reader(input.as_bytes()).unwrap();
+            let bytes_almanac = bytes::parse_almanac(input.as_bytes()).unwrap();
+            prop_assert_eq!(lowest_location(&bytes_almanac), lowest_location(&almanac));
+            prop_assert_eq!(
+                lowest_location_of_ranges(&bytes_almanac),
+                lowest_location_of_ranges(&almanac)
+            );
+        }
+
+        #[test]
+        fn bytes_and_lines_reject_the_same_input(input in "(seeds:( [0-9]{1,21}){0,4}\n\n)?([a-z-]{0,30} map:\n([0-9]{1,21} [0-9]{1,21} [0-9]{1,21}\n){0,3}\n?){0,8}") {
+            prop_assert_eq!(
+                bytes::parse_almanac(input.as_bytes()).is_ok(),
+                parse_almanac_from_reader(input.as_bytes()).is_ok()
+            );
+        }
+    }
+}
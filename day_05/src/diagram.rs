@@ -0,0 +1,99 @@
+//! Pictures of the maps of the almanac as SVG.
+
+use crate::Almanac;
+
+const WIDTH: f64 = 1000.0;
+const MARGIN: f64 = 20.0;
+const SECTION_HEIGHT: f64 = 140.0;
+const SOURCE_AXIS: f64 = 34.0;
+const DESTINATION_AXIS: f64 = 114.0;
+
+/// Draws every map of the almanac as an interval diagram. Each map gets a
+/// band with its source ranges on the upper axis and their destination ranges
+/// on the lower axis, joined by one coloured shape per entry. Both axes of a
+/// band share one scale, from 0 to the highest range end of that map.
+pub fn svg(almanac: &Almanac) -> String {
+    let height = SECTION_HEIGHT * almanac.mapping_routine_vec.len() as f64;
+    let mut output = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\" font-family=\"sans-serif\" font-size=\"12\">\n",
+        WIDTH, height
+    );
+    output.push_str(&format!(
+        "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+        WIDTH, height
+    ));
+
+    for (i, (map_vec, map_vec_name)) in almanac.mapping_routine_vec.iter().enumerate() {
+        let top = i as f64 * SECTION_HEIGHT;
+        let max = map_vec
+            .iter()
+            .flat_map(|mapping| {
+                [
+                    mapping.source_range_start + mapping.range_length,
+                    mapping.destination_range_start + mapping.range_length,
+                ]
+            })
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let x = |value: u64| MARGIN + value as f64 / max as f64 * (WIDTH - 2.0 * MARGIN);
+
+        output.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" font-weight=\"bold\">{} ({} entries)</text>\n",
+            MARGIN,
+            top + 16.0,
+            map_vec_name,
+            map_vec.len()
+        ));
+        for mapping in map_vec {
+            let hue = (mapping.source_range_start as f64 / max as f64 * 300.0) as u32;
+            output.push_str(&format!(
+                "<polygon points=\"{:.1},{} {:.1},{} {:.1},{} {:.1},{}\" fill=\"hsl({}, 70%, 55%)\" fill-opacity=\"0.6\" stroke=\"hsl({}, 70%, 35%)\" stroke-width=\"0.5\"/>\n",
+                x(mapping.source_range_start),
+                top + SOURCE_AXIS,
+                x(mapping.source_range_start + mapping.range_length),
+                top + SOURCE_AXIS,
+                x(mapping.destination_range_start + mapping.range_length),
+                top + DESTINATION_AXIS,
+                x(mapping.destination_range_start),
+                top + DESTINATION_AXIS,
+                hue,
+                hue
+            ));
+        }
+        for (axis, label) in [(SOURCE_AXIS, "source"), (DESTINATION_AXIS, "destination")] {
+            output.push_str(&format!(
+                "<line x1=\"{}\" y1=\"{y}\" x2=\"{}\" y2=\"{y}\" stroke=\"black\"/>\n",
+                MARGIN,
+                WIDTH - MARGIN,
+                y = top + axis
+            ));
+            output.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\" text-anchor=\"end\" fill=\"#666666\">{} 0 – {}</text>\n",
+                WIDTH - MARGIN,
+                top + axis + if axis == SOURCE_AXIS { -4.0 } else { 14.0 },
+                label,
+                max
+            ));
+        }
+    }
+    output.push_str("</svg>\n");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::svg;
+    use crate::parse_almanac;
+
+    #[test]
+    fn svg_example() {
+        let almanac = parse_almanac("puzzle01_input_test").unwrap();
+        let svg = svg(&almanac);
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<polygon ").count(), 18);
+        assert!(svg.contains("seed-to-soil map (2 entries)"));
+        assert!(svg.contains("humidity-to-location (2 entries)"));
+    }
+}
@@ -0,0 +1,89 @@
+//! Random almanacs for tests and benchmarks.
+
+use crate::SECTIONS;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+// Lays out ranges of the given lengths one after another, separated by
+// random gaps, and returns their start values.
+fn layout(lengths: &[u64], rng: &mut ChaCha8Rng) -> Vec<u64> {
+    let mut cursor = rng.gen_range(0..1_000);
+    lengths
+        .iter()
+        .map(|length| {
+            let start = cursor;
+            cursor += length + rng.gen_range(0..=50);
+            start
+        })
+        .collect()
+}
+
+/// Generates an almanac with `size` seed ranges and `size` entries in each of
+/// the seven maps. Source ranges and destination ranges of a map never
+/// overlap each other.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+    let lengths: Vec<Vec<u64>> = (0..SECTIONS.len())
+        .map(|_| (0..size).map(|_| rng.gen_range(1..=1_000)).collect())
+        .collect();
+    let span = lengths
+        .iter()
+        .map(|section| section.iter().sum::<u64>() + 51 * size as u64)
+        .max()
+        .unwrap_or(0)
+        + 1_000;
+
+    let seeds: Vec<String> = (0..size)
+        .map(|_| format!("{} {}", rng.gen_range(0..span), rng.gen_range(1..=100)))
+        .collect();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for ((section_line, _), lengths) in SECTIONS.iter().zip(lengths) {
+        let sources = layout(&lengths, &mut rng);
+        let mut order: Vec<usize> = (0..lengths.len()).collect();
+        order.shuffle(&mut rng);
+        let shuffled_lengths: Vec<u64> = order.iter().map(|i| lengths[*i]).collect();
+        let mut destinations = vec![0; lengths.len()];
+        for (i, destination) in order.iter().zip(layout(&shuffled_lengths, &mut rng)) {
+            destinations[*i] = destination;
+        }
+
+        input.push_str(&format!("\n{}\n", section_line));
+        for ((source, destination), length) in sources.iter().zip(destinations).zip(lengths) {
+            input.push_str(&format!("{} {} {}\n", destination, source, length));
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+    use crate::{lowest_location, lowest_location_of_ranges, parse_almanac_from_reader};
+
+    #[test]
+    fn maps_have_one_entry_per_seed_range() {
+        let input = generate(50, 6);
+        assert_eq!(input, generate(50, 6));
+        let almanac = parse_almanac_from_reader(input.as_bytes()).unwrap();
+        assert_eq!(almanac.seeds().len(), 2 * 50);
+        let maps = almanac.inverse_mapping_routine_vec();
+        assert_eq!(maps.len(), 7);
+        assert!(maps.iter().all(|(mappings, _)| mappings.len() == 50));
+        // The inverse maps swap sources and destinations, so this checks
+        // both for overlaps.
+        for (mappings, _) in almanac.mapping_routine_vec.iter().chain(&maps) {
+            let mut ranges: Vec<(u64, u64)> = mappings
+                .iter()
+                .map(|m| (m.source_range_start, m.range_length))
+                .collect();
+            ranges.sort();
+            assert!(ranges.windows(2).all(|r| r[0].0 + r[0].1 <= r[1].0));
+        }
+        // Part 1 also reads the range lengths as seeds, hence its low
+        // location.
+        assert_eq!(lowest_location(&almanac), Ok(1));
+        assert_eq!(lowest_location_of_ranges(&almanac), Ok(9));
+    }
+}
@@ -0,0 +1,951 @@
+//! Day 5: If You Give A Seed A Fertilizer. The almanac maps seeds through
+//! seven maps, from seed to soil and on to location. Part 1 looks for the
+//! lowest location of the seeds, part 2 reads the seeds as ranges.
+//!
+//! Values in no source range of a map keep their number:
+//!
+//! ```
+//! let mut input = String::from("seeds: 79 14 55 13\n\nseed-to-soil map:\n52 50 48\n");
+//! for (section_line, _) in &day_05::SECTIONS[1..] {
+//!     input.push_str(&format!("\n{}\n", section_line));
+//! }
+//! let almanac = day_05::parse_almanac_from_reader(input.as_bytes()).unwrap();
+//! assert_eq!(almanac.location(79), Ok(81));
+//! assert_eq!(day_05::lowest_location(&almanac), Ok(13));
+//! assert_eq!(day_05::lowest_location_of_ranges(&almanac), Ok(57));
+//! ```
+
+#![warn(missing_docs)]
+
+pub mod bytes;
+pub mod diagram;
+pub mod gen;
+mod progress;
+
+use aoc_parse::{
+    labelled, many, map, number, pair, parse_all, sections, tag, token, Input, ParseError, Parser,
+};
+use log::debug;
+use progress::Progress;
+use rayon::prelude::*;
+use std::{
+    fmt,
+    fs::File,
+    io::{BufRead, BufReader},
+    ops::Range,
+    path::Path,
+};
+
+/// One entry of a map: `range_length` values from `source_range_start` on
+/// map to the same number of values from `destination_range_start` on.
+#[derive(Debug, Clone)]
+pub struct Mapping {
+    destination_range_start: u64,
+    source_range_start: u64,
+    range_length: u64,
+}
+
+impl Mapping {
+    /// An entry in the order of the almanac, destination first. None if the
+    /// source or the destination range ends past `u64::MAX`, which keeps all
+    /// arithmetic on entries from overflowing.
+    ///
+    /// ```
+    /// use day_05::Mapping;
+    ///
+    /// assert!(Mapping::new(52, 50, 48).is_some());
+    /// assert!(Mapping::new(0, u64::MAX, 2).is_none());
+    /// ```
+    pub fn new(
+        destination_range_start: u64,
+        source_range_start: u64,
+        range_length: u64,
+    ) -> Option<Mapping> {
+        source_range_start.checked_add(range_length)?;
+        destination_range_start.checked_add(range_length)?;
+        Some(Mapping {
+            destination_range_start,
+            source_range_start,
+            range_length,
+        })
+    }
+
+    fn source_range(&self) -> Range<u64> {
+        self.source_range_start..self.source_range_start + self.range_length
+    }
+
+    /// Where the entry maps `input`, None if `input` is outside of the source
+    /// range.
+    ///
+    /// ```
+    /// let mapping = day_05::Mapping::new(52, 50, 48).unwrap();
+    /// assert_eq!(mapping.map(79), Some(81));
+    /// assert_eq!(mapping.map(98), None);
+    /// ```
+    pub fn map(&self, input: u64) -> Option<u64> {
+        if self.source_range().contains(&input) {
+            Some(input - self.source_range_start + self.destination_range_start)
+        } else {
+            None
+        }
+    }
+
+    /// The same entry read backwards, from its destination to its source.
+    pub fn inverse(&self) -> Mapping {
+        Mapping {
+            destination_range_start: self.source_range_start,
+            source_range_start: self.destination_range_start,
+            range_length: self.range_length,
+        }
+    }
+}
+
+impl fmt::Display for Mapping {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.destination_range_start, self.source_range_start, self.range_length
+        )
+    }
+}
+
+/// One map applied to a value while tracing a seed.
+#[derive(Debug)]
+pub struct TraceStep {
+    /// The name of the map, e.g. "seed-to-soil map".
+    pub map_name: &'static str,
+    /// The category the map leads to, e.g. "soil".
+    pub category: &'static str,
+    /// The value before the map.
+    pub input: u64,
+    /// The value after the map.
+    pub output: u64,
+    /// The index and the entry of the map that matched, or None when the
+    /// value was outside of every source range and kept as is.
+    pub entry: Option<(usize, Mapping)>,
+}
+
+impl fmt::Display for TraceStep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<24} {:>12} -> {} {} (",
+            self.map_name, self.input, self.category, self.output
+        )?;
+        match &self.entry {
+            Some((i, mapping)) => write!(f, "entry {}: {})", i + 1, mapping),
+            None => write!(f, "no entry, unchanged)"),
+        }
+    }
+}
+
+// The category a map leads to, e.g. "soil" for "seed-to-soil map".
+fn destination_category(map_vec_name: &'static str) -> &'static str {
+    let name = map_vec_name.trim_end_matches(" map");
+    match name.split_once("-to-") {
+        Some((_, category)) => category,
+        None => name,
+    }
+}
+
+// All values an inverted map leads back to. Values outside of every original
+// source range map to themselves, so a value has several preimages when it is
+// both inside a destination range and outside of all source ranges.
+fn preimages(inverse_map_vec: &[Mapping], value: u64) -> Vec<u64> {
+    let mut preimages: Vec<u64> = inverse_map_vec
+        .iter()
+        .filter_map(|mapping| mapping.map(value))
+        .collect();
+    if !inverse_map_vec
+        .iter()
+        .any(|mapping| mapping.inverse().source_range().contains(&value))
+    {
+        preimages.push(value);
+    }
+    preimages
+}
+
+/// The seeds and the seven maps of an almanac, in the order they apply.
+#[derive(Debug)]
+pub struct Almanac {
+    seed_vec: Vec<u64>,
+    mapping_routine_vec: Vec<(Vec<Mapping>, &'static str)>,
+}
+
+/// The line starting each map of the almanac and the name errors and traces
+/// use for it, in the order the maps apply.
+pub const SECTIONS: [(&str, &str); 7] = [
+    ("seed-to-soil map:", "seed-to-soil map"),
+    ("soil-to-fertilizer map:", "soil-to-fertilizer"),
+    ("fertilizer-to-water map:", "fertilizer-to-water"),
+    ("water-to-light map:", "water-to-light"),
+    ("light-to-temperature map:", "light-to-temperature"),
+    ("temperature-to-humidity map:", "temperature-to-humidity"),
+    ("humidity-to-location map:", "humidity-to-location"),
+];
+
+fn entry<'a>() -> impl Parser<'a, (u64, u64, u64)> {
+    let value = || token(number::<u64>());
+    map(pair(value(), pair(value(), value())), |(d, (s, l))| {
+        (d, s, l)
+    })
+}
+
+/// Parses one map of the almanac. `lines` is one blank-line-separated block:
+/// `section_line` followed by one entry per line. Errors are in the context
+/// of `map_vec_name`.
+///
+/// ```
+/// let block = &aoc_parse::sections("seed-to-soil map:\n50 98 2\n52 50 48\n")[0];
+/// let map_vec =
+///     day_05::parse_mapping_section("seed-to-soil map:", "seed-to-soil map", block).unwrap();
+/// assert_eq!(map_vec.len(), 2);
+/// assert_eq!(map_vec[1].map(79), Some(81));
+/// ```
+pub fn parse_mapping_section(
+    section_line: &str,
+    map_vec_name: &str,
+    lines: &[Input],
+) -> Result<Vec<Mapping>, ParseError> {
+    let (header, number_lines) = match lines.split_first() {
+        Some(split) => split,
+        None => return Ok(Vec::new()),
+    };
+    parse_all(tag(section_line), *header).map_err(|e| e.context(map_vec_name))?;
+    let mut map_vec = Vec::new();
+    for line in number_lines {
+        let (destination_range_start, source_range_start, range_length) =
+            parse_all(entry(), *line).map_err(|e| e.context(map_vec_name))?;
+        let Some(mapping) = Mapping::new(destination_range_start, source_range_start, range_length)
+        else {
+            return Err(line
+                .error("Range exceeds the u64 limit")
+                .context(map_vec_name));
+        };
+        map_vec.push(mapping);
+    }
+    Ok(map_vec)
+}
+
+/// Reads the almanac in the file at `filename`.
+pub fn parse_almanac<P>(filename: P) -> Result<Almanac, String>
+where
+    P: AsRef<Path>,
+{
+    let file = match File::open(filename) {
+        Ok(file) => file,
+        Err(_) => return Err(String::from("Error opening file")),
+    };
+    parse_almanac_from_reader(BufReader::new(file))
+}
+
+/// Reads the almanac in `buf`: the seeds, then every map of [`SECTIONS`] in
+/// that order, each block separated by a blank line. Errors point at the
+/// line and column of the input.
+pub fn parse_almanac_from_reader<R>(mut buf: R) -> Result<Almanac, String>
+where
+    R: BufRead,
+{
+    let mut text = String::new();
+    if buf.read_to_string(&mut text).is_err() {
+        return Err(String::from("Could not read the almanac"));
+    }
+    let mut sections = sections(&text).into_iter();
+
+    let Some(seed_section) = sections.next() else {
+        return Err(String::from("Seed line does not exist"));
+    };
+    let seed_vec = parse_all(
+        labelled("seeds:", many(token(number::<u64>()))),
+        seed_section[0],
+    )?;
+    if let Some(line) = seed_section.get(1) {
+        return Err(line.error("Expected a blank line after the seeds").into());
+    }
+
+    let mut mapping_routine_vec = Vec::new();
+    for (section_line, map_vec_name) in SECTIONS {
+        let Some(lines) = sections.next() else {
+            return Err(format!("{} section is missing", map_vec_name));
+        };
+        mapping_routine_vec.push((
+            parse_mapping_section(section_line, map_vec_name, &lines)?,
+            map_vec_name,
+        ));
+    }
+    if let Some(lines) = sections.next() {
+        return Err(lines[0]
+            .error("Expected the end of the almanac after the last map")
+            .into());
+    }
+    debug!(
+        "Parsed {} seeds and {} map entries",
+        seed_vec.len(),
+        mapping_routine_vec
+            .iter()
+            .map(|(map_vec, _)| map_vec.len())
+            .sum::<usize>()
+    );
+
+    Ok(Almanac {
+        seed_vec,
+        mapping_routine_vec,
+    })
+}
+
+impl Almanac {
+    /// The seeds as listed, which part 2 reads as pairs of start and length.
+    pub fn seeds(&self) -> &[u64] {
+        &self.seed_vec
+    }
+
+    /// Follows `seed` through every map. Fails if entries of a map overlap
+    /// at a value on the way.
+    pub fn location(&self, seed: u64) -> Result<u64, String> {
+        let mut location_number = seed;
+        for (map_vec, map_vec_name) in &self.mapping_routine_vec {
+            let mut v: Vec<u64> = map_vec
+                .iter()
+                .filter_map(|mapping| mapping.map(location_number))
+                .collect();
+            if v.len() == 1 {
+                location_number = v.pop().unwrap();
+            } else if 1 < v.len() {
+                return Err(format!(
+                    "There was more than one function mapping for the seed. Number of mappings: {}. Mapping Name: {}", v.len(), map_vec_name
+                ));
+            }
+        }
+        Ok(location_number)
+    }
+
+    /// Every step of the way from a seed to its location.
+    ///
+    /// ```
+    /// let almanac = day_05::parse_almanac("puzzle01_input_test").unwrap();
+    /// let trace = almanac.trace(79).unwrap();
+    /// assert_eq!((trace[0].category, trace[0].output), ("soil", 81));
+    /// assert_eq!(trace.last().unwrap().output, almanac.location(79).unwrap());
+    /// ```
+    pub fn trace(&self, seed: u64) -> Result<Vec<TraceStep>, String> {
+        let mut trace = Vec::new();
+        let mut value = seed;
+        for (map_vec, map_vec_name) in &self.mapping_routine_vec {
+            let mut matches: Vec<(usize, &Mapping, u64)> = map_vec
+                .iter()
+                .enumerate()
+                .filter_map(|(i, mapping)| mapping.map(value).map(|output| (i, mapping, output)))
+                .collect();
+            if 1 < matches.len() {
+                return Err(format!(
+                    "There was more than one function mapping for the seed. Number of mappings: {}. Mapping Name: {}", matches.len(), map_vec_name
+                ));
+            }
+            let (output, entry) = match matches.pop() {
+                Some((i, mapping, output)) => (output, Some((i, mapping.clone()))),
+                None => (value, None),
+            };
+            trace.push(TraceStep {
+                map_name: map_vec_name,
+                category: destination_category(map_vec_name),
+                input: value,
+                output,
+                entry,
+            });
+            value = output;
+        }
+        Ok(trace)
+    }
+
+    /// The maps in reverse order with every entry inverted, leading from
+    /// locations back to seeds.
+    pub fn inverse_mapping_routine_vec(&self) -> Vec<(Vec<Mapping>, &'static str)> {
+        self.mapping_routine_vec
+            .iter()
+            .rev()
+            .map(|(map_vec, map_vec_name)| {
+                (
+                    map_vec.iter().map(Mapping::inverse).collect(),
+                    *map_vec_name,
+                )
+            })
+            .collect()
+    }
+
+    /// Every value, sorted, that ends up at `location`.
+    pub fn seeds_of_location(&self, location: u64) -> Vec<u64> {
+        seeds_of_location(&self.inverse_mapping_routine_vec(), location)
+    }
+
+    fn seed_ranges(&self) -> Result<Vec<Range<u64>>, String> {
+        self.seed_vec
+            .chunks_exact(2)
+            .map(
+                |seed_range| match seed_range[0].checked_add(seed_range[1]) {
+                    Some(seed_end) => Ok(seed_range[0]..seed_end),
+                    None => Err(format!(
+                        "Seed range starting at {} exceeds the u64 limit",
+                        seed_range[0]
+                    )),
+                },
+            )
+            .collect()
+    }
+
+    // Maps whole ranges of seeds at once. Parts of a range covered by a
+    // mapping are shifted to its destination, the rest maps to itself.
+    fn location_ranges(&self, seed_ranges: Vec<Range<u64>>) -> Result<Vec<Range<u64>>, String> {
+        let mut ranges = seed_ranges;
+        for (map_vec, map_vec_name) in &self.mapping_routine_vec {
+            let mut mapped_ranges = Vec::new();
+            for range in ranges {
+                let mut covered_ranges = Vec::new();
+                for mapping in map_vec {
+                    let start = range.start.max(mapping.source_range_start);
+                    let end = range.end.min(mapping.source_range().end);
+                    if start < end {
+                        covered_ranges.push(start..end);
+                        mapped_ranges.push(
+                            start - mapping.source_range_start + mapping.destination_range_start
+                                ..end - mapping.source_range_start
+                                    + mapping.destination_range_start,
+                        );
+                    }
+                }
+                covered_ranges.sort_by_key(|covered_range| covered_range.start);
+                let mut uncovered_start = range.start;
+                for covered_range in covered_ranges {
+                    if covered_range.start < uncovered_start {
+                        return Err(format!(
+                            "There was more than one function mapping for the seed range. Mapping Name: {}",
+                            map_vec_name
+                        ));
+                    }
+                    if uncovered_start < covered_range.start {
+                        mapped_ranges.push(uncovered_start..covered_range.start);
+                    }
+                    uncovered_start = covered_range.end;
+                }
+                if uncovered_start < range.end {
+                    mapped_ranges.push(uncovered_start..range.end);
+                }
+            }
+            ranges = mapped_ranges;
+        }
+        Ok(ranges)
+    }
+}
+
+/// The lowest location of any seed of the almanac.
+pub fn lowest_location(almanac: &Almanac) -> Result<u64, String> {
+    let mut lowest_location_number: Option<u64> = None;
+    for seed in &almanac.seed_vec {
+        let location_number = almanac.location(*seed)?;
+        match lowest_location_number {
+            Some(number) => {
+                if location_number < number {
+                    lowest_location_number = Some(location_number);
+                }
+            }
+            None => lowest_location_number = Some(location_number),
+        }
+    }
+    match lowest_location_number {
+        Some(number) => Ok(number),
+        None => Err(String::from("no lowest location number found")),
+    }
+}
+
+/// The lowest location of any seed, reading the seeds as pairs of the start
+/// and the length of a range. Maps whole ranges at once, so the time does not
+/// depend on the length of the ranges.
+pub fn lowest_location_of_ranges(almanac: &Almanac) -> Result<u64, String> {
+    let seed_ranges = almanac
+        .seed_ranges()?
+        .into_iter()
+        .filter(|seed_range| !seed_range.is_empty())
+        .collect();
+    let location_ranges = almanac.location_ranges(seed_ranges)?;
+    debug!("Seeds map to {} location ranges", location_ranges.len());
+    match location_ranges
+        .iter()
+        .map(|location_range| location_range.start)
+        .min()
+    {
+        Some(number) => Ok(number),
+        None => Err(String::from("no lowest location number found")),
+    }
+}
+
+/// Same as [`lowest_location_of_ranges`], but maps every single seed of the
+/// ranges. Kept as the reference for [`lowest_location_of_ranges`].
+pub fn lowest_location_of_ranges_naive(almanac: &Almanac) -> Result<u64, String> {
+    let mut lowest_location_number: Option<u64> = None;
+    for seed_range in almanac.seed_ranges()? {
+        debug!(
+            "Starting seed: {} Range: {}",
+            seed_range.start,
+            seed_range.end - seed_range.start
+        );
+        for seed_num in seed_range {
+            let location_number = almanac.location(seed_num)?;
+            match lowest_location_number {
+                Some(number) => {
+                    if location_number < number {
+                        lowest_location_number = Some(location_number);
+                    }
+                }
+                None => lowest_location_number = Some(location_number),
+            }
+        }
+    }
+    match lowest_location_number {
+        Some(number) => Ok(number),
+        None => Err(String::from("no lowest location number found")),
+    }
+}
+
+// Seeds per work item of `lowest_location_of_ranges_naive_parallel`.
+const CHUNK_SIZE: u64 = 1 << 20;
+
+fn run_in_pool<T, F>(threads: usize, f: F) -> Result<T, String>
+where
+    T: Send,
+    F: FnOnce() -> T + Send,
+{
+    match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+        Ok(pool) => Ok(pool.install(f)),
+        Err(e) => Err(format!("Could not build thread pool: {}", e)),
+    }
+}
+
+// Combines the results of the work items in their original order, so the
+// first error is reported no matter which thread finished first.
+fn lowest_of(results: Vec<Result<Option<u64>, String>>) -> Result<u64, String> {
+    let mut lowest_location_number: Option<u64> = None;
+    for result in results {
+        if let Some(location_number) = result? {
+            lowest_location_number = Some(match lowest_location_number {
+                Some(number) => number.min(location_number),
+                None => location_number,
+            });
+        }
+    }
+    match lowest_location_number {
+        Some(number) => Ok(number),
+        None => Err(String::from("no lowest location number found")),
+    }
+}
+
+// Sub-intervals per thread that `lowest_location_of_ranges_parallel` splits
+// every seed range into.
+const INTERVALS_PER_THREAD: u64 = 4;
+
+// Splits `range` into `pieces` sub-intervals of about the same length, or
+// into single seeds if it is shorter than that.
+fn split_range(range: &Range<u64>, pieces: u64) -> Vec<Range<u64>> {
+    let length = range.end - range.start;
+    let pieces = pieces.clamp(1, length.max(1));
+    let bound =
+        |i: u64| range.start + (u128::from(length) * u128::from(i) / u128::from(pieces)) as u64;
+    (0..pieces).map(|i| bound(i)..bound(i + 1)).collect()
+}
+
+/// [`lowest_location_of_ranges`] with every seed range split into
+/// sub-intervals that are spread over `threads` threads, 0 meaning one per
+/// CPU, so a few long ranges still keep all threads busy. With `progress` the
+/// number of finished sub-intervals is shown on stderr.
+pub fn lowest_location_of_ranges_parallel(
+    almanac: &Almanac,
+    threads: usize,
+    progress: bool,
+) -> Result<u64, String> {
+    let seed_ranges: Vec<Range<u64>> = almanac
+        .seed_ranges()?
+        .into_iter()
+        .filter(|seed_range| !seed_range.is_empty())
+        .collect();
+    let results = run_in_pool(threads, || {
+        let pieces = rayon::current_num_threads() as u64 * INTERVALS_PER_THREAD;
+        let intervals: Vec<Range<u64>> = seed_ranges
+            .iter()
+            .flat_map(|seed_range| split_range(seed_range, pieces))
+            .collect();
+        debug!(
+            "Split {} seed ranges into {} intervals",
+            seed_ranges.len(),
+            intervals.len()
+        );
+        let progress = Progress::new("Seed intervals", intervals.len() as u64, progress);
+        intervals
+            .par_iter()
+            .map(|interval| {
+                let result =
+                    almanac
+                        .location_ranges(vec![interval.clone()])
+                        .map(|location_ranges| {
+                            location_ranges
+                                .iter()
+                                .map(|location_range| location_range.start)
+                                .min()
+                        });
+                progress.advance(1);
+                result
+            })
+            .collect()
+    })?;
+    lowest_of(results)
+}
+
+/// [`lowest_location_of_ranges_naive`] with the seeds split into chunks that
+/// are spread over `threads` threads, 0 meaning one per CPU. With `progress`
+/// the number of mapped seeds is shown on stderr.
+pub fn lowest_location_of_ranges_naive_parallel(
+    almanac: &Almanac,
+    threads: usize,
+    progress: bool,
+) -> Result<u64, String> {
+    let mut chunks = Vec::new();
+    for seed_range in almanac.seed_ranges()? {
+        let mut chunk_start = seed_range.start;
+        while chunk_start < seed_range.end {
+            let chunk_end = seed_range.end.min(chunk_start.saturating_add(CHUNK_SIZE));
+            chunks.push(chunk_start..chunk_end);
+            chunk_start = chunk_end;
+        }
+    }
+    let total = chunks.iter().map(|chunk| chunk.end - chunk.start).sum();
+    debug!("Split {} seeds into {} chunks", total, chunks.len());
+    let progress = Progress::new("Seeds", total, progress);
+    let results = run_in_pool(threads, || {
+        chunks
+            .par_iter()
+            .map(|chunk| {
+                let mut lowest_location_number: Option<u64> = None;
+                for seed_num in chunk.clone() {
+                    let location_number = almanac.location(seed_num)?;
+                    lowest_location_number = Some(match lowest_location_number {
+                        Some(number) => number.min(location_number),
+                        None => location_number,
+                    });
+                }
+                progress.advance(chunk.end - chunk.start);
+                Ok(lowest_location_number)
+            })
+            .collect()
+    })?;
+    lowest_of(results)
+}
+
+fn seeds_of_location(
+    inverse_mapping_routine_vec: &[(Vec<Mapping>, &'static str)],
+    location: u64,
+) -> Vec<u64> {
+    let mut values = vec![location];
+    for (inverse_map_vec, _) in inverse_mapping_routine_vec {
+        values = values
+            .into_iter()
+            .flat_map(|value| preimages(inverse_map_vec, value))
+            .collect();
+        values.sort();
+        values.dedup();
+    }
+    values
+}
+
+/// Walks the locations upwards from 0 and stops at the first one leading
+/// back to a seed inside one of the seed ranges. An independent alternative
+/// to [`lowest_location_of_ranges`], but slow for high locations.
+pub fn lowest_location_inverse(almanac: &Almanac) -> Result<u64, String> {
+    let seed_ranges = almanac.seed_ranges()?;
+    if seed_ranges.iter().all(|seed_range| seed_range.is_empty()) {
+        return Err(String::from("no lowest location number found"));
+    }
+    let inverse_mapping_routine_vec = almanac.inverse_mapping_routine_vec();
+    for location in 0..=u64::MAX {
+        if seeds_of_location(&inverse_mapping_routine_vec, location)
+            .iter()
+            .any(|seed| {
+                seed_ranges
+                    .iter()
+                    .any(|seed_range| seed_range.contains(seed))
+            })
+        {
+            return Ok(location);
+        }
+    }
+    Err(String::from("no lowest location number found"))
+}
+
+/// Solves part 1 for the input in the file at `filename`.
+pub fn puzzle01<P>(filename: P) -> Result<u64, String>
+where
+    P: AsRef<Path>,
+{
+    lowest_location(&parse_almanac(filename)?)
+}
+
+/// Solves part 2 for the input in the file at `filename`.
+pub fn puzzle02<P>(filename: P) -> Result<u64, String>
+where
+    P: AsRef<Path>,
+{
+    lowest_location_of_ranges(&parse_almanac(filename)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        gen, lowest_location, lowest_location_inverse, lowest_location_of_ranges,
+        lowest_location_of_ranges_naive, lowest_location_of_ranges_naive_parallel,
+        lowest_location_of_ranges_parallel, parse_almanac, parse_almanac_from_reader, split_range,
+        Almanac, Mapping, SECTIONS,
+    };
+    use proptest::prelude::*;
+
+    // Almanacs with small numbers and without overlapping source ranges, so
+    // the naive solver stays fast.
+    fn almanac() -> impl Strategy<Value = Almanac> {
+        (
+            prop::collection::vec(0u64..200, 0..10),
+            prop::collection::vec(
+                prop::collection::vec((0u64..200, 0u64..20, 1u64..30), 0..5),
+                SECTIONS.len(),
+            ),
+        )
+            .prop_map(|(seed_vec, sections)| Almanac {
+                seed_vec,
+                mapping_routine_vec: sections
+                    .into_iter()
+                    .zip(SECTIONS)
+                    .map(|(entries, (_, map_vec_name))| {
+                        let mut source_range_start = 0;
+                        let map_vec = entries
+                            .into_iter()
+                            .map(|(destination_range_start, gap, range_length)| {
+                                source_range_start += gap;
+                                let mapping = Mapping {
+                                    destination_range_start,
+                                    source_range_start,
+                                    range_length,
+                                };
+                                source_range_start += range_length;
+                                mapping
+                            })
+                            .collect();
+                        (map_vec, map_vec_name)
+                    })
+                    .collect(),
+            })
+    }
+
+    aoc_examples::example_tests!(|path| crate::puzzle01(path).unwrap(), |path| {
+        crate::puzzle02(path).unwrap()
+    });
+
+    #[test]
+    fn inverse_search() {
+        let almanac = parse_almanac("puzzle01_input_test").unwrap();
+        assert_eq!(lowest_location_inverse(&almanac), Ok(46));
+        assert_eq!(almanac.seeds_of_location(46), vec![82]);
+        for seed in [79, 14, 55, 13] {
+            let location = almanac.location(seed).unwrap();
+            assert!(almanac.seeds_of_location(location).contains(&seed));
+        }
+    }
+
+    #[test]
+    fn trace_of_seed_79() {
+        let almanac = parse_almanac("puzzle01_input_test").unwrap();
+        let trace = almanac.trace(79).unwrap();
+        let steps: Vec<(&str, u64, Option<usize>)> = trace
+            .iter()
+            .map(|step| {
+                (
+                    step.category,
+                    step.output,
+                    step.entry.as_ref().map(|(i, _)| *i),
+                )
+            })
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                ("soil", 81, Some(1)),
+                ("fertilizer", 81, None),
+                ("water", 81, None),
+                ("light", 74, Some(1)),
+                ("temperature", 78, Some(2)),
+                ("humidity", 78, None),
+                ("location", 82, Some(0)),
+            ]
+        );
+        assert_eq!(trace[0].input, 79);
+        assert_eq!(trace[0].entry.as_ref().unwrap().1.to_string(), "52 50 48");
+        assert_eq!(
+            trace[0].to_string(),
+            "seed-to-soil map                   79 -> soil 81 (entry 2: 52 50 48)"
+        );
+        assert_eq!(
+            trace[1].to_string(),
+            "soil-to-fertilizer                 81 -> fertilizer 81 (no entry, unchanged)"
+        );
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let almanac = parse_almanac("puzzle01_input_test").unwrap();
+        for threads in [0, 1, 3] {
+            assert_eq!(
+                lowest_location_of_ranges_parallel(&almanac, threads, false),
+                Ok(46)
+            );
+            assert_eq!(
+                lowest_location_of_ranges_naive_parallel(&almanac, threads, false),
+                Ok(46)
+            );
+        }
+    }
+
+    #[test]
+    fn seed_ranges_split_into_adjacent_intervals() {
+        assert_eq!(split_range(&(10..20), 3), vec![10..13, 13..16, 16..20]);
+        assert_eq!(split_range(&(10..12), 8), vec![10..11, 11..12]);
+        let intervals = split_range(&(u64::MAX - 7..u64::MAX), 4);
+        assert_eq!(
+            intervals.first().map(|interval| interval.start),
+            Some(u64::MAX - 7)
+        );
+        assert_eq!(
+            intervals.last().map(|interval| interval.end),
+            Some(u64::MAX)
+        );
+        assert!(intervals
+            .windows(2)
+            .all(|pair| pair[0].end == pair[1].start));
+    }
+
+    // An almanac with the given seed line and a single entry in the first map.
+    fn almanac_with_entry(seeds: &str, entry: &str) -> Result<Almanac, String> {
+        let mut input = format!("seeds: {}\n\n{}\n{}\n", seeds, SECTIONS[0].0, entry);
+        for (section_line, _) in &SECTIONS[1..] {
+            input.push_str(&format!("\n{}\n", section_line));
+        }
+        parse_almanac_from_reader(input.as_bytes())
+    }
+
+    #[test]
+    fn overflowing_entries_are_rejected() {
+        let max = u64::MAX;
+        assert!(almanac_with_entry("1", &format!("0 {} 2", max - 1)).is_err());
+        assert!(almanac_with_entry("1", &format!("{} 0 2", max - 1)).is_err());
+        assert!(almanac_with_entry("1", &format!("0 0 {}", max)).is_ok());
+        assert!(almanac_with_entry("1", &format!("0 1 {}", max)).is_err());
+        assert!(almanac_with_entry("1", "0 0 18446744073709551616").is_err());
+    }
+
+    #[test]
+    fn errors_point_at_the_input() {
+        assert_eq!(
+            almanac_with_entry("1", &format!("0 {} 2", u64::MAX - 1)).unwrap_err(),
+            "line 4, column 1: seed-to-soil map: Range exceeds the u64 limit"
+        );
+        assert_eq!(
+            almanac_with_entry("1", "0 0 x").unwrap_err(),
+            "line 4, column 5: seed-to-soil map: expected a digit, found 'x'"
+        );
+        assert_eq!(
+            almanac_with_entry("1 x", "0 0 1").unwrap_err(),
+            "line 1, column 10: expected the end of the line, found 'x'"
+        );
+    }
+
+    #[test]
+    fn mapping_at_the_upper_edge() {
+        let max = u64::MAX;
+        let entry = format!("0 {} 10", max - 10);
+        let almanac = almanac_with_entry(&format!("{} {}", max - 1, max - 10), &entry).unwrap();
+        assert_eq!(almanac.location(max - 10), Ok(0));
+        assert_eq!(almanac.location(max - 1), Ok(9));
+        assert_eq!(almanac.location(max), Ok(max));
+        assert_eq!(lowest_location(&almanac), Ok(0));
+
+        let almanac = almanac_with_entry(&format!("{} 10", max - 10), &entry).unwrap();
+        assert_eq!(lowest_location_of_ranges(&almanac), Ok(0));
+        assert_eq!(lowest_location_of_ranges_naive(&almanac), Ok(0));
+
+        let entry = format!("{} 0 10", max - 10);
+        let almanac = almanac_with_entry("0 10", &entry).unwrap();
+        assert_eq!(almanac.location(9), Ok(max - 1));
+        assert_eq!(lowest_location_of_ranges(&almanac), Ok(max - 10));
+    }
+
+    #[test]
+    fn overflowing_seed_ranges_are_errors() {
+        let max = u64::MAX;
+        let almanac = almanac_with_entry(&format!("{} 2", max - 1), "0 0 1").unwrap();
+        assert!(lowest_location_of_ranges(&almanac).is_err());
+        assert!(lowest_location_of_ranges_naive(&almanac).is_err());
+        let almanac = almanac_with_entry(&format!("{} 1", max - 1), "0 0 1").unwrap();
+        assert_eq!(lowest_location_of_ranges(&almanac), Ok(max - 1));
+    }
+
+    proptest! {
+        #[test]
+        fn parser_never_panics(input in "(seeds:( [0-9]{1,21}){0,4}\n\n)?([a-z-]{0,30} map:\n([0-9]{1,21} [0-9]{1,21} [0-9]{1,21}\n){0,3}\n?){0,8}") {
+            let _ = parse_almanac_from_reader(input.as_bytes());
+        }
+
+        #[test]
+        fn lowest_location_of_ranges_matches_naive(almanac in almanac()) {
+            prop_assert_eq!(
+                lowest_location_of_ranges(&almanac).ok(),
+                lowest_location_of_ranges_naive(&almanac).ok()
+            );
+        }
+
+        #[test]
+        fn parallel_matches_naive(almanac in almanac(), threads in 1usize..4) {
+            let expected = lowest_location_of_ranges_naive(&almanac).ok();
+            prop_assert_eq!(
+                lowest_location_of_ranges_parallel(&almanac, threads, false).ok(),
+                expected
+            );
+            prop_assert_eq!(
+                lowest_location_of_ranges_naive_parallel(&almanac, threads, false).ok(),
+                expected
+            );
+        }
+
+        #[test]
+        fn lowest_location_inverse_matches_ranges(almanac in almanac()) {
+            if let Ok(location) = lowest_location_of_ranges(&almanac) {
+                prop_assert_eq!(lowest_location_inverse(&almanac), Ok(location));
+            }
+        }
+
+        #[test]
+        fn seeds_of_location_inverts_location(almanac in almanac(), seed in 0u64..300) {
+            let location = almanac.location(seed).unwrap();
+            prop_assert!(almanac.seeds_of_location(location).contains(&seed));
+        }
+
+        #[test]
+        fn trace_ends_at_location(almanac in almanac(), seed in 0u64..300) {
+            let trace = almanac.trace(seed).unwrap();
+            prop_assert_eq!(trace.len(), SECTIONS.len());
+            prop_assert!(trace.windows(2).all(|steps| steps[0].output == steps[1].input));
+            prop_assert_eq!(trace.last().unwrap().output, almanac.location(seed).unwrap());
+        }
+
+        #[test]
+        fn lowest_location_of_ranges_matches_naive_on_generated_input(size in 1usize..20, seed: u64) {
+            let almanac = parse_almanac_from_reader(gen::generate(size, seed).as_bytes()).unwrap();
+            prop_assert_eq!(
+                lowest_location_of_ranges(&almanac),
+                lowest_location_of_ranges_naive(&almanac)
+            );
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::{
+    io::{self, Write},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+// Progress of a long running computation, printed to stderr as a single line
+// that is updated whenever another percent is done. Safe to share between
+// threads.
+pub(crate) struct Progress {
+    label: &'static str,
+    total: u64,
+    done: AtomicU64,
+    percent: AtomicU64,
+    enabled: bool,
+}
+
+impl Progress {
+    pub(crate) fn new(label: &'static str, total: u64, enabled: bool) -> Progress {
+        let progress = Progress {
+            label,
+            total,
+            done: AtomicU64::new(0),
+            percent: AtomicU64::new(0),
+            enabled,
+        };
+        progress.print(0, 0);
+        progress
+    }
+
+    pub(crate) fn advance(&self, amount: u64) {
+        let done = self.done.fetch_add(amount, Ordering::Relaxed) + amount;
+        let percent = if self.total == 0 {
+            100
+        } else {
+            (done as u128 * 100 / self.total as u128) as u64
+        };
+        if self.percent.fetch_max(percent, Ordering::Relaxed) < percent {
+            self.print(done, percent);
+        }
+    }
+
+    fn print(&self, done: u64, percent: u64) {
+        if self.enabled {
+            let mut stderr = io::stderr().lock();
+            let _ = write!(
+                stderr,
+                "\r{}: {}/{} ({}%)",
+                self.label, done, self.total, percent
+            );
+            let _ = stderr.flush();
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.enabled {
+            eprintln!();
+        }
+    }
+}
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    for line in day_01::read_lines_from_reader(data).unwrap_or_default() {
+        let _ = day_01::first_and_last_digit(&line);
+    }
+    let _ = day_01::bytes::sum_digits_and_words::<u64>(data);
+});
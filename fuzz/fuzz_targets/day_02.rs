@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = day_02::get_games_from_reader(data);
+    let _ = day_02::bytes::get_games(data);
+});
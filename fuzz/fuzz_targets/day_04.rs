@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = day_04::get_match_counts_from_reader(data);
+    let _ = day_04::bytes::get_match_counts(data);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(almanac) = day_05::parse_almanac_from_reader(data) {
+        let _ = day_05::lowest_location(&almanac);
+        let _ = day_05::lowest_location_of_ranges(&almanac);
+    }
+    let _ = day_05::bytes::parse_almanac(data);
+});